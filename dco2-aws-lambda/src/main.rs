@@ -4,7 +4,7 @@ use anyhow::Context;
 use figment::{Figment, providers::Env};
 use lambda_http::{Error, run, tracing};
 
-use dco2::github::{AppConfig, GHClientOctorust};
+use dco2::github::{AppConfig, ConfigPolicy, GHClientOctorust};
 use dco2_server::handlers::setup_router;
 
 #[tokio::main]
@@ -19,11 +19,14 @@ async fn main() -> Result<(), Error> {
     tracing::init_default_subscriber();
 
     // Setup GitHub client
-    let cfg: AppConfig = Figment::new()
-        .merge(Env::prefixed("DCO2_"))
-        .extract()
-        .context("error setting up configuration")?;
-    let gh_client = Arc::new(GHClientOctorust::new(&cfg).context("error setting up github client")?);
+    let figment = Figment::new().merge(Env::prefixed("DCO2_").split("__"));
+    let cfg: AppConfig = figment.extract().context("error setting up configuration")?;
+    let policy: ConfigPolicy = if figment.contains("policy") {
+        figment.extract_inner("policy").context("error setting up policy")?
+    } else {
+        ConfigPolicy::default()
+    };
+    let gh_client = Arc::new(GHClientOctorust::new(&cfg, policy).context("error setting up github client")?);
 
     // Start lambda runtime
     let router = setup_router(gh_client, &cfg.webhook_secret);
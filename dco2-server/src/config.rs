@@ -9,13 +9,15 @@ use figment::{
 };
 use serde::{Deserialize, Serialize};
 
-use dco2::github::AppConfig;
+use dco2::github::{AppConfig, ConfigPolicy};
 
 /// Server configuration.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub(crate) struct Config {
     pub github_app: AppConfig,
     pub log_format: LogFormat,
+    #[serde(default)]
+    pub policy: ConfigPolicy,
     pub server_addr: String,
 }
 
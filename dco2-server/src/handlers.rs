@@ -79,8 +79,15 @@ async fn event(
     };
 
     // Process event and run DCO check
-    if let Err(err) = dco::process_event(gh_client, &event).await {
+    if let Err(err) = dco::process_event(gh_client.clone(), &event).await {
         error!(?err, "error processing event");
+
+        // Make the error visible in GitHub, so that it doesn't go unnoticed
+        let event_id = headers.get(EVENT_ID_HEADER).and_then(|id| id.to_str().ok());
+        if let Err(report_err) = dco::report_error(gh_client, &event, event_id, &err).await {
+            error!(err = ?report_err, "error reporting event processing error");
+        }
+
         return Err((StatusCode::INTERNAL_SERVER_ERROR, String::new()));
     }
     info!("event processed successfully");
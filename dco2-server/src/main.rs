@@ -39,7 +39,8 @@ async fn main() -> Result<()> {
     }
 
     // Setup GitHub client
-    let gh_client = GHClientOctorust::new(&cfg.github_app).context("error setting up github client")?;
+    let gh_client =
+        GHClientOctorust::new(&cfg.github_app, cfg.policy).context("error setting up github client")?;
     let gh_client = Arc::new(gh_client);
 
     // Setup and launch HTTP server
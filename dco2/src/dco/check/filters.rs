@@ -2,6 +2,17 @@
 
 use super::{CommitCheckOutput, CommitError};
 
+/// Template filter to convert the first character of a string to uppercase,
+/// leaving the rest of it untouched.
+#[askama::filter_fn]
+pub(crate) fn capitalize_first(s: String, _: &dyn askama::Values) -> askama::Result<String> {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => Ok(first.to_uppercase().chain(chars).collect()),
+        None => Ok(s),
+    }
+}
+
 /// Template filter to check if any of the commits contain any of the
 /// provided errors.
 #[askama::filter_fn]
@@ -24,9 +35,26 @@ pub(crate) fn truncate_no_dots(s: String, _: &dyn askama::Values, length: usize)
 mod tests {
     use crate::dco::check::{
         CommitCheckOutput, CommitError,
-        filters::{contains_error, truncate_no_dots},
+        filters::{capitalize_first, contains_error, truncate_no_dots},
     };
 
+    #[test]
+    fn capitalize_first_works() {
+        assert_eq!(
+            "Skipped: only exempt paths modified (docs/README.md)".to_string(),
+            capitalize_first::default()
+                .execute(
+                    "skipped: only exempt paths modified (docs/README.md)".to_string(),
+                    askama::NO_VALUES
+                )
+                .unwrap()
+        );
+        assert_eq!(
+            String::new(),
+            capitalize_first::default().execute(String::new(), askama::NO_VALUES).unwrap()
+        );
+    }
+
     #[test]
     fn contains_error_one_commit_error_found() {
         let commits = vec![CommitCheckOutput {
@@ -9,19 +9,27 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::github::{Commit, Config, User};
+use crate::github::{
+    Commit, Config, ConfigCommitDate, ConfigError, ConfigMode, ConfigReportAs, ConfigSource,
+    DEFAULT_ENFORCE_AFTER_DATE_SOURCE, DEFAULT_MODE, DEFAULT_REPORT_AS, RepositoryPermission, User,
+};
 
 mod filters;
 #[cfg(test)]
 mod tests;
 
+/// Marker used to identify the failure comment posted on pull requests.
+pub(crate) const FAILURE_COMMENT_MARKER: &str = "<!-- dco2-failure-comment -->";
+
 /// Check input.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) struct CheckInput {
+    pub cherry_picked_commits: Vec<Commit>,
     pub commits: Vec<Commit>,
     pub config: Config,
     pub head_ref: String,
     pub members: Vec<String>,
+    pub pull_request_description: Option<String>,
 }
 
 /// Check output.
@@ -35,6 +43,296 @@ pub(crate) struct CheckOutput {
     pub only_last_commit_contains_errors: bool,
 }
 
+impl CheckOutput {
+    /// Get a short description of the check result, suitable for a commit
+    /// status.
+    pub(crate) fn status_description(&self) -> String {
+        let mut description = match self.num_commits_with_errors {
+            0 => "All commits are signed off".to_string(),
+            n if n == self.commits.len() => "All commits are incorrectly signed off".to_string(),
+            1 => "One commit is incorrectly signed off".to_string(),
+            n => format!("{n} commits are incorrectly signed off"),
+        };
+        if self.num_commits_with_errors > 0 && self.config.advisory_mode_is_enabled() {
+            description.push_str(" (advisory mode)");
+        }
+        description
+    }
+
+    /// Check if any of the commits relied on a sign-off found in the pull
+    /// request description to pass the check.
+    pub(crate) fn pull_request_description_signoffs_used(&self) -> bool {
+        self.commits
+            .iter()
+            .any(|c| c.success_reason == Some(CommitSuccessReason::ValidSignOffInPullRequestDescription))
+    }
+}
+
+/// Merge group check output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Template)]
+#[template(path = "merge_group.md", whitespace = "suppress")]
+pub(crate) struct MergeGroupCheckOutput {
+    pub commits: Vec<CommitCheckOutput>,
+    pub config: Config,
+    pub num_commits_with_errors: usize,
+    pub pull_requests: Vec<i64>,
+}
+
+/// Direct push check output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Template)]
+#[template(path = "push.md", whitespace = "suppress")]
+pub(crate) struct PushCheckOutput {
+    pub branch: String,
+    pub commits: Vec<CommitCheckOutput>,
+    pub config: Config,
+    pub num_commits_with_errors: usize,
+}
+
+/// Issue opened when the commits pushed directly to a branch do not pass the
+/// check (when enabled in the configuration).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Template)]
+#[template(path = "push_issue.md", whitespace = "suppress")]
+pub(crate) struct PushIssue {
+    pub branch: String,
+    pub commits: Vec<CommitCheckOutput>,
+    pub num_commits_with_errors: usize,
+    pub pusher: String,
+}
+
+/// Check explanation, including the details of the commits with errors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Template)]
+#[template(path = "explain.md", whitespace = "suppress")]
+pub(crate) struct CheckExplanation {
+    pub commits: Vec<CommitExplanation>,
+}
+
+impl CheckExplanation {
+    /// Create a new check explanation from the check output provided.
+    pub(crate) fn new(output: &CheckOutput) -> Self {
+        let format_user = |user: &User| format!("{} <{}>", user.name, user.email);
+
+        let commits = output
+            .commits
+            .iter()
+            .filter(|c| !c.errors.is_empty())
+            .map(|c| {
+                let author = c.commit.author.as_ref().map(format_user);
+                CommitExplanation {
+                    author: author.clone(),
+                    commit: c.commit.clone(),
+                    committer: c.commit.committer.as_ref().map(format_user),
+                    errors: c.errors.clone(),
+                    remediation: author
+                        .as_ref()
+                        .filter(|_| output.config.individual_remediation_commits_are_allowed())
+                        .map(|author| {
+                            format!(
+                                "I, {author}, hereby add my Signed-off-by to this commit: {}",
+                                c.commit.sha
+                            )
+                        }),
+                    signoff: author.as_ref().map(|author| format!("Signed-off-by: {author}")),
+                    signoffs_found: get_signoffs(&c.commit.message)
+                        .into_iter()
+                        .map(|signoff| format!("{} <{}>", signoff.name, signoff.email))
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Self { commits }
+    }
+}
+
+/// Failure comment, posted on pull requests that do not pass the check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Template)]
+#[template(path = "failure_comment.md", whitespace = "suppress")]
+pub(crate) struct CheckFailureComment {
+    pub commits: Vec<CommitCheckOutput>,
+    pub config: Config,
+    pub head_ref: String,
+    pub num_commits_with_errors: usize,
+    pub only_last_commit_contains_errors: bool,
+}
+
+impl CheckFailureComment {
+    /// Create a new failure comment from the check output provided.
+    pub(crate) fn new(output: &CheckOutput) -> Self {
+        Self {
+            commits: output.commits.clone(),
+            config: output.config.clone(),
+            head_ref: output.head_ref.clone(),
+            num_commits_with_errors: output.num_commits_with_errors,
+            only_last_commit_contains_errors: output.only_last_commit_contains_errors,
+        }
+    }
+}
+
+/// Configuration preview, added to the check output of pull requests that
+/// modify the configuration file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Template)]
+#[template(path = "config_preview.md", whitespace = "suppress")]
+pub(crate) struct ConfigPreview {
+    pub commits: Vec<CommitCheckOutput>,
+    pub config: Config,
+    pub error: Option<String>,
+    pub ignored_files: Vec<String>,
+    pub num_commits_with_errors: usize,
+    pub source: Option<ConfigSource>,
+}
+
+impl ConfigPreview {
+    /// Create a new configuration preview from the output of checking the
+    /// pull request commits using the new configuration (read from the
+    /// configuration file provided, if any). The configuration files modified
+    /// that do not take effect must be provided in `ignored_files`.
+    pub(crate) fn new(
+        output: &CheckOutput,
+        source: Option<ConfigSource>,
+        ignored_files: Vec<String>,
+    ) -> Self {
+        Self {
+            commits: output.commits.clone(),
+            config: output.config.clone(),
+            error: None,
+            ignored_files,
+            num_commits_with_errors: output.num_commits_with_errors,
+            source,
+        }
+    }
+
+    /// Create a new configuration preview for a configuration file that is
+    /// not valid.
+    pub(crate) fn invalid(
+        err: &ConfigError,
+        source: Option<ConfigSource>,
+        ignored_files: Vec<String>,
+    ) -> Self {
+        let error = match err {
+            ConfigError::Validation(errors) => errors.join("\n"),
+            ConfigError::Parse { .. } => err.to_string(),
+        };
+        Self {
+            commits: vec![],
+            config: Config::default(),
+            error: Some(error),
+            ignored_files,
+            num_commits_with_errors: 0,
+            source,
+        }
+    }
+
+    /// Get the effective settings of the new configuration, as a list of
+    /// key and value pairs (sorted by key).
+    pub(crate) fn settings(&self) -> Vec<(&'static str, String)> {
+        let config = &self.config;
+        let list = |values: Option<&Vec<String>>, default: &str| match values {
+            Some(values) if !values.is_empty() => {
+                values.iter().map(|v| format!("`{v}`")).collect::<Vec<_>>().join(", ")
+            }
+            _ => default.to_string(),
+        };
+        let text = |value: Option<&str>| value.map_or("-".to_string(), |v| format!("`{v}`"));
+        let flag = |value: bool| format!("`{value}`");
+
+        let allowed_by = config.override_allowed_by.as_ref();
+        let branches = config.branches.as_ref();
+        let date_source =
+            match config.enforce_after_date_source.as_ref().unwrap_or(&DEFAULT_ENFORCE_AFTER_DATE_SOURCE) {
+                ConfigCommitDate::Author => "author",
+                ConfigCommitDate::Committer => "committer",
+            };
+        let mode = match config.mode.as_ref().unwrap_or(&DEFAULT_MODE) {
+            ConfigMode::Enforce => "enforce",
+            ConfigMode::Advisory => "advisory",
+        };
+        let minimum_permission = allowed_by.and_then(|a| a.minimum_permission).map(|p| match p {
+            RepositoryPermission::None => "none",
+            RepositoryPermission::Read => "read",
+            RepositoryPermission::Triage => "triage",
+            RepositoryPermission::Write => "write",
+            RepositoryPermission::Maintain => "maintain",
+            RepositoryPermission::Admin => "admin",
+        });
+        let report_as = match config.report_as.as_ref().unwrap_or(&DEFAULT_REPORT_AS) {
+            ConfigReportAs::CheckRun => "check_run",
+            ConfigReportAs::CommitStatus => "commit_status",
+            ConfigReportAs::Both => "both",
+        };
+
+        vec![
+            ("_extends", text(config.extends.as_deref())),
+            ("allowCherryPicks", flag(config.cherry_picks_are_allowed())),
+            ("allowOverrideAction", flag(config.override_action_is_allowed())),
+            (
+                "allowRemediationCommits.individual",
+                flag(config.individual_remediation_commits_are_allowed()),
+            ),
+            (
+                "allowRemediationCommits.thirdParty",
+                flag(config.third_party_remediation_commits_are_allowed()),
+            ),
+            (
+                "branches.exclude",
+                list(branches.and_then(|b| b.exclude.as_ref()), "-"),
+            ),
+            (
+                "branches.include",
+                list(branches.and_then(|b| b.include.as_ref()), "all branches"),
+            ),
+            ("checkMergeGroup", flag(config.merge_group_check_is_enabled())),
+            (
+                "checkPushes.branches",
+                list(
+                    config.check_pushes.as_ref().and_then(|c| c.branches.as_ref()),
+                    "-",
+                ),
+            ),
+            ("checkPushes.openIssue", flag(config.push_issue_is_enabled())),
+            ("commentOnFailure", flag(config.failure_comment_is_enabled())),
+            ("deferDrafts", flag(config.drafts_are_deferred())),
+            (
+                "enforceAfter",
+                text(config.enforce_after.map(|d| d.to_string()).as_deref()),
+            ),
+            ("enforceAfterDateSource", text(Some(date_source))),
+            ("exemptPaths", list(config.exempt_paths.as_ref(), "-")),
+            ("exemptionLabel", text(config.exemption_label())),
+            ("failureLabel", text(config.failure_label())),
+            ("mode", text(Some(mode))),
+            ("overrideAllowedBy.minimumPermission", text(minimum_permission)),
+            (
+                "overrideAllowedBy.teams",
+                list(allowed_by.and_then(|a| a.teams.as_ref()), "-"),
+            ),
+            (
+                "overrideAllowedBy.users",
+                list(allowed_by.and_then(|a| a.users.as_ref()), "-"),
+            ),
+            ("reportAs", text(Some(report_as))),
+            ("require.members", flag(config.members_signoff_is_required())),
+            (
+                "requireOverrideJustification",
+                flag(config.override_justification_is_required()),
+            ),
+            ("squashMerge", flag(config.squash_merge_is_enabled())),
+            ("useBaseBranchConfig", flag(config.base_branch_config_is_used())),
+        ]
+    }
+}
+
+/// Commit explanation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CommitExplanation {
+    pub author: Option<String>,
+    pub commit: Commit,
+    pub committer: Option<String>,
+    pub errors: Vec<CommitError>,
+    pub remediation: Option<String>,
+    pub signoff: Option<String>,
+    pub signoffs_found: Vec<String>,
+}
+
 /// Commit check output.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) struct CommitCheckOutput {
@@ -70,22 +368,38 @@ pub(crate) enum CommitError {
 /// Reasons why a commit's check succeeded.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) enum CommitSuccessReason {
+    BeforeEnforcementDate,
     FromBot,
     FromMember,
     IsMerge,
+    OnlyExemptPathsModified(Vec<String>),
     ValidSignOff,
+    ValidSignOffInCherryPickedCommit,
+    ValidSignOffInPullRequestDescription,
     ValidSignOffInRemediationCommit,
 }
 
 impl Display for CommitSuccessReason {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            CommitSuccessReason::BeforeEnforcementDate => {
+                write!(f, "skipped: commit predates DCO enforcement")
+            }
             CommitSuccessReason::FromBot => write!(f, "skipped: sign-off not required in bot commit"),
             CommitSuccessReason::FromMember => {
                 write!(f, "skipped: sign-off not required for members")
             }
             CommitSuccessReason::IsMerge => write!(f, "skipped: sign-off not required in merge commit"),
+            CommitSuccessReason::OnlyExemptPathsModified(patterns) => {
+                write!(f, "skipped: only exempt paths modified ({})", patterns.join(", "))
+            }
             CommitSuccessReason::ValidSignOff => write!(f, "valid sign-off found"),
+            CommitSuccessReason::ValidSignOffInCherryPickedCommit => {
+                write!(f, "valid sign-off found in cherry-picked commit")
+            }
+            CommitSuccessReason::ValidSignOffInPullRequestDescription => {
+                write!(f, "valid sign-off found in pull request description")
+            }
             CommitSuccessReason::ValidSignOffInRemediationCommit => {
                 write!(f, "valid sign-off found in remediation commit")
             }
@@ -106,6 +420,12 @@ pub(crate) fn check(input: &CheckInput) -> CheckOutput {
     // Get remediations from all commits
     let remediations = get_remediations(&input.config, &input.commits);
 
+    // Get sign-offs from the pull request description (squash merge mode)
+    let pull_request_description_signoffs = match &input.pull_request_description {
+        Some(description) if input.config.squash_merge_is_enabled() => get_signoffs(description),
+        _ => Vec::new(),
+    };
+
     // Check each commit
     for commit in &input.commits {
         let mut commit_output = CommitCheckOutput::new(commit.clone());
@@ -128,7 +448,7 @@ pub(crate) fn check(input: &CheckInput) -> CheckOutput {
         };
 
         // Check if sign-off is present
-        let signoffs = get_signoffs(commit);
+        let signoffs = get_signoffs(&commit.message);
         if signoffs.is_empty() {
             commit_output.errors.push(CommitError::SignOffNotFound);
         }
@@ -148,6 +468,23 @@ pub(crate) fn check(input: &CheckInput) -> CheckOutput {
             commit_output.success_reason = Some(CommitSuccessReason::ValidSignOffInRemediationCommit);
         }
 
+        // Check if the sign-off is present in the original cherry-picked commit
+        if commit_output.success_reason.is_none()
+            && input.config.cherry_picks_are_allowed()
+            && cherry_picked_commits_match(&input.cherry_picked_commits, commit)
+        {
+            commit_output.errors.clear();
+            commit_output.success_reason = Some(CommitSuccessReason::ValidSignOffInCherryPickedCommit);
+        }
+
+        // Check if the sign-off is present in the pull request description
+        if commit_output.success_reason.is_none()
+            && signoffs_match(&pull_request_description_signoffs, commit)
+        {
+            commit_output.errors.clear();
+            commit_output.success_reason = Some(CommitSuccessReason::ValidSignOffInPullRequestDescription);
+        }
+
         // Track commit
         output.commits.push(commit_output);
     }
@@ -174,6 +511,16 @@ fn should_skip_commit(check_input: &CheckInput, commit: &Commit) -> (bool, Optio
         return (true, Some(CommitSuccessReason::FromBot));
     }
 
+    // Skip commits older than the enforcement date (if any)
+    if check_input.config.commit_predates_enforcement(commit) {
+        return (true, Some(CommitSuccessReason::BeforeEnforcementDate));
+    }
+
+    // Skip commits that only modify exempt paths
+    if let Some(patterns) = check_input.config.exempt_paths_matched(commit) {
+        return (true, Some(CommitSuccessReason::OnlyExemptPathsModified(patterns)));
+    }
+
     // Skip verified commits from members if the feature is enabled
     if !check_input.config.members_signoff_is_required() && commit.verified.unwrap_or(false) {
         // Check if the commit's author is a member
@@ -245,11 +592,11 @@ impl SignOff {
     }
 }
 
-/// Get sign-offs found in the commit message.
-fn get_signoffs(commit: &Commit) -> Vec<SignOff> {
+/// Get sign-offs found in the message provided.
+fn get_signoffs(message: &str) -> Vec<SignOff> {
     let mut signoffs = Vec::new();
 
-    for (_, [name, email]) in SIGN_OFF.captures_iter(&commit.message).map(|c| c.extract()) {
+    for (_, [name, email]) in SIGN_OFF.captures_iter(message).map(|c| c.extract()) {
         signoffs.push(SignOff {
             name: name.to_string(),
             email: email.to_string(),
@@ -399,3 +746,34 @@ fn get_remediations(config: &Config, commits: &[Commit]) -> Vec<Remediation> {
 fn remediations_match(remediations: &[Remediation], commit: &Commit) -> bool {
     remediations.iter().any(|remediation| remediation.matches_commit(commit))
 }
+
+/// Cherry-pick reference regular expression (added by `git cherry-pick -x`).
+static CHERRY_PICK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?mi)^\(cherry picked from commit ([0-9a-f]{7,40})\)\s*$")
+        .expect("expr in CHERRY_PICK to be valid")
+});
+
+/// Get the SHAs of the original commits referenced in the cherry-picked
+/// commit message provided.
+pub(crate) fn get_cherry_picked_shas(message: &str) -> Vec<String> {
+    CHERRY_PICK
+        .captures_iter(message)
+        .map(|c| c.extract())
+        .map(|(_, [sha])| sha.to_lowercase())
+        .collect()
+}
+
+/// Check if any of the original commits referenced by the cherry-picked
+/// commit provided has a valid sign-off. The original commit must have been
+/// authored by the same author, and its sign-off must match them.
+fn cherry_picked_commits_match(cherry_picked_commits: &[Commit], commit: &Commit) -> bool {
+    get_cherry_picked_shas(&commit.message).iter().any(|sha| {
+        cherry_picked_commits.iter().any(|original| {
+            original.sha.starts_with(sha.as_str())
+                && original.author.as_ref().is_some_and(|author| author.matches(&commit.author))
+                && get_signoffs(&original.message)
+                    .iter()
+                    .any(|signoff| signoff.matches_user(&original.author))
+        })
+    })
+}
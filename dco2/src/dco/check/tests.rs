@@ -5,7 +5,7 @@ use pretty_assertions::assert_eq;
 
 use crate::{
     dco::check::{CheckInput, CheckOutput, CommitCheckOutput, CommitError, CommitSuccessReason, check},
-    github::{Commit, Config, ConfigAllowRemediationCommits, ConfigRequire, User},
+    github::{Commit, Config, ConfigAllowRemediationCommits, ConfigCommitDate, ConfigRequire, User},
 };
 
 #[test]
@@ -16,10 +16,12 @@ fn single_commit_no_signoff_is_merge_commit() {
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -50,10 +52,12 @@ fn single_commit_no_signoff_author_is_bot() {
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -91,10 +95,12 @@ fn single_commit_no_signoff_author_is_member() {
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec!["user1".to_string()],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -132,10 +138,12 @@ fn single_commit_no_signoff_author_is_member_but_members_are_required_to_signoff
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec!["user1".to_string()],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -173,10 +181,373 @@ fn single_commit_no_signoff_author_is_member_but_the_commit_is_not_verified() {
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec!["user1".to_string()],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![CommitError::SignOffNotFound],
+                success_reason: None,
+            }],
+            config,
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 1,
+            only_last_commit_contains_errors: true,
+        }
+    );
+}
+
+#[test]
+fn single_commit_no_signoff_author_date_before_enforcement_date() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        author_date: Some("2024-05-31T23:59:59Z".parse().unwrap()),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer_date: Some("2024-07-01T10:00:00Z".parse().unwrap()),
+        message: "Test commit message".to_string(),
+        ..Default::default()
+    };
+
+    let config = Config {
+        enforce_after: Some("2024-06-01".parse().unwrap()),
+        ..Default::default()
+    };
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: config.clone(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![],
+                success_reason: Some(CommitSuccessReason::BeforeEnforcementDate),
+            }],
+            config,
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 0,
+            only_last_commit_contains_errors: false,
+        }
+    );
+}
+
+#[test]
+fn single_commit_no_signoff_author_date_after_enforcement_date() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        author_date: Some("2024-06-01T00:00:00Z".parse().unwrap()),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer_date: Some("2024-06-01T00:00:00Z".parse().unwrap()),
+        message: "Test commit message".to_string(),
+        ..Default::default()
+    };
+
+    let config = Config {
+        enforce_after: Some("2024-06-01".parse().unwrap()),
+        ..Default::default()
+    };
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: config.clone(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![CommitError::SignOffNotFound],
+                success_reason: None,
+            }],
+            config,
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 1,
+            only_last_commit_contains_errors: true,
+        }
+    );
+}
+
+#[test]
+fn single_commit_no_signoff_committer_date_before_enforcement_date() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        author_date: Some("2024-07-01T10:00:00Z".parse().unwrap()),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer_date: Some("2024-05-31T10:00:00Z".parse().unwrap()),
+        message: "Test commit message".to_string(),
+        ..Default::default()
+    };
+
+    let config = Config {
+        enforce_after: Some("2024-06-01".parse().unwrap()),
+        enforce_after_date_source: Some(ConfigCommitDate::Committer),
+        ..Default::default()
+    };
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: config.clone(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![],
+                success_reason: Some(CommitSuccessReason::BeforeEnforcementDate),
+            }],
+            config,
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 0,
+            only_last_commit_contains_errors: false,
+        }
+    );
+}
+
+#[test]
+fn single_commit_no_signoff_committer_date_after_enforcement_date() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        author_date: Some("2024-05-31T10:00:00Z".parse().unwrap()),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer_date: Some("2024-07-01T10:00:00Z".parse().unwrap()),
+        message: "Test commit message".to_string(),
+        ..Default::default()
+    };
+
+    let config = Config {
+        enforce_after: Some("2024-06-01".parse().unwrap()),
+        enforce_after_date_source: Some(ConfigCommitDate::Committer),
+        ..Default::default()
+    };
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: config.clone(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![CommitError::SignOffNotFound],
+                success_reason: None,
+            }],
+            config,
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 1,
+            only_last_commit_contains_errors: true,
+        }
+    );
+}
+
+#[test]
+fn single_commit_no_signoff_only_exempt_paths_modified() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        files: Some(vec![
+            "docs/guide/intro.md".to_string(),
+            "website/index.html".to_string(),
+        ]),
+        message: "Test commit message".to_string(),
+        ..Default::default()
+    };
+
+    let config = Config {
+        exempt_paths: Some(vec![
+            "docs/**".to_string(),
+            "website/**".to_string(),
+            "*.md".to_string(),
+        ]),
+        ..Default::default()
+    };
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: config.clone(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![],
+                success_reason: Some(CommitSuccessReason::OnlyExemptPathsModified(vec![
+                    "docs/**".to_string(),
+                    "*.md".to_string(),
+                    "website/**".to_string()
+                ])),
+            }],
+            config,
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 0,
+            only_last_commit_contains_errors: false,
+        }
+    );
+}
+
+#[test]
+fn single_commit_no_signoff_exempt_and_non_exempt_paths_modified() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        files: Some(vec!["docs/guide/intro.md".to_string(), "src/main.rs".to_string()]),
+        message: "Test commit message".to_string(),
+        ..Default::default()
+    };
+
+    let config = Config {
+        exempt_paths: Some(vec![
+            "docs/**".to_string(),
+            "website/**".to_string(),
+            "*.md".to_string(),
+        ]),
+        ..Default::default()
+    };
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: config.clone(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![CommitError::SignOffNotFound],
+                success_reason: None,
+            }],
+            config,
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 1,
+            only_last_commit_contains_errors: true,
+        }
+    );
+}
+
+#[test]
+fn single_commit_no_signoff_no_files_modified() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        files: Some(vec![]),
+        message: "Test commit message".to_string(),
+        ..Default::default()
+    };
+
+    let config = Config {
+        exempt_paths: Some(vec![
+            "docs/**".to_string(),
+            "website/**".to_string(),
+            "*.md".to_string(),
+        ]),
+        ..Default::default()
+    };
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: config.clone(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -201,28 +572,267 @@ fn single_commit_valid_signoff_author_match() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
-            email: "user1@email.test".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer: Some(User {
+            name: "user2".to_string(),
+            email: "user2@email.test".to_string(),
+            ..Default::default()
+        }),
+        message: indoc! {r"
+            Test commit message
+
+            Signed-off-by: user1 <user1@email.test>
+        "}
+        .to_string(),
+        ..Default::default()
+    };
+
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: Default::default(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![],
+                success_reason: Some(CommitSuccessReason::ValidSignOff),
+            }],
+            config: Default::default(),
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 0,
+            only_last_commit_contains_errors: false,
+        }
+    );
+}
+
+#[test]
+fn single_commit_valid_signoff_committer_match() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user2".to_string(),
+            email: "user2@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        message: indoc! {r"
+            Test commit message
+
+            Signed-off-by: user1 <user1@email.test>
+        "}
+        .to_string(),
+        ..Default::default()
+    };
+
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: Default::default(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![],
+                success_reason: Some(CommitSuccessReason::ValidSignOff),
+            }],
+            config: Default::default(),
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 0,
+            only_last_commit_contains_errors: false,
+        }
+    );
+}
+
+#[test]
+fn single_commit_valid_signoff_multiple_signoffs() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        message: indoc! {r"
+            Test commit message
+
+            Signed-off-by: userx <userx@email.test>
+            Signed-off-by: user1 <user1@email.test>
+            Signed-off-by: usery <usery@email.test>
+        "}
+        .to_string(),
+        ..Default::default()
+    };
+
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: Default::default(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![],
+                success_reason: Some(CommitSuccessReason::ValidSignOff),
+            }],
+            config: Default::default(),
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 0,
+            only_last_commit_contains_errors: false,
+        }
+    );
+}
+
+#[test]
+fn single_commit_valid_signoff_signoff_case_insensitive() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        message: indoc! {r"
+            Test commit message
+
+            signed-off-by: USER1 <user1@email.test>
+        "}
+        .to_string(),
+        ..Default::default()
+    };
+
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: Default::default(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![],
+                success_reason: Some(CommitSuccessReason::ValidSignOff),
+            }],
+            config: Default::default(),
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 0,
+            only_last_commit_contains_errors: false,
+        }
+    );
+}
+
+#[test]
+fn single_commit_valid_signoff_signoff_trailing_whitespace() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        message: "Test\n\nSigned-off-by: user1 <user1@email.test>   ".to_string(),
+        ..Default::default()
+    };
+
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: Default::default(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![],
+                success_reason: Some(CommitSuccessReason::ValidSignOff),
+            }],
+            config: Default::default(),
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 0,
+            only_last_commit_contains_errors: false,
+        }
+    );
+}
+
+#[test]
+fn single_commit_valid_signoff_email_contains_subdomain() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.some.test".to_string(),
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user2".to_string(),
-            email: "user2@email.test".to_string(),
+            name: "user1".to_string(),
+            email: "user1@email.some.test".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1 <user1@email.test>
+            Signed-off-by: user1 <user1@email.some.test>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -243,32 +853,34 @@ fn single_commit_valid_signoff_author_match() {
 }
 
 #[test]
-fn single_commit_valid_signoff_committer_match() {
+fn single_commit_valid_signoff_github_bot_noreply_email() {
     let commit1 = Commit {
         author: Some(User {
-            name: "user2".to_string(),
-            email: "user2@email.test".to_string(),
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user1".to_string(),
-            email: "user1@email.test".to_string(),
+            name: "github-actions[bot]".to_string(),
+            email: "12345678+github-actions[bot]@users.noreply.github.com".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1 <user1@email.test>
+            Signed-off-by: github-actions[bot] <12345678+github-actions[bot]@users.noreply.github.com>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -289,34 +901,34 @@ fn single_commit_valid_signoff_committer_match() {
 }
 
 #[test]
-fn single_commit_valid_signoff_multiple_signoffs() {
+fn single_commit_valid_signoff_email_contains_plus_alias() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
-            email: "user1@email.test".to_string(),
+            email: "user1+alias@email.test".to_string(),
             ..Default::default()
         }),
         committer: Some(User {
             name: "user1".to_string(),
-            email: "user1@email.test".to_string(),
+            email: "user1+alias@email.test".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: userx <userx@email.test>
-            Signed-off-by: user1 <user1@email.test>
-            Signed-off-by: usery <usery@email.test>
+            Signed-off-by: user1 <user1+alias@email.test>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -337,32 +949,34 @@ fn single_commit_valid_signoff_multiple_signoffs() {
 }
 
 #[test]
-fn single_commit_valid_signoff_signoff_case_insensitive() {
+fn single_commit_invalid_author_email() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
-            email: "user1@email.test".to_string(),
+            email: "invalid".to_string(),
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user1".to_string(),
-            email: "user1@email.test".to_string(),
+            name: "user2".to_string(),
+            email: "user2@email.test".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            signed-off-by: USER1 <user1@email.test>
+            Signed-off-by: user2 <user2@email.test>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -371,39 +985,41 @@ fn single_commit_valid_signoff_signoff_case_insensitive() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![],
-                success_reason: Some(CommitSuccessReason::ValidSignOff),
+                errors: vec![CommitError::InvalidAuthorEmail],
+                success_reason: None,
             }],
             config: Default::default(),
             head_ref: "main".to_string(),
-            num_commits_with_errors: 0,
-            only_last_commit_contains_errors: false,
+            num_commits_with_errors: 1,
+            only_last_commit_contains_errors: true,
         }
     );
 }
 
 #[test]
-fn single_commit_valid_signoff_signoff_trailing_whitespace() {
+fn single_commit_invalid_author_email_and_no_signoff() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
-            email: "user1@email.test".to_string(),
+            email: "invalid".to_string(),
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user1".to_string(),
-            email: "user1@email.test".to_string(),
+            name: "user2".to_string(),
+            email: "user2@email.test".to_string(),
             ..Default::default()
         }),
-        message: "Test\n\nSigned-off-by: user1 <user1@email.test>   ".to_string(),
+        message: "Test commit message".to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -412,44 +1028,46 @@ fn single_commit_valid_signoff_signoff_trailing_whitespace() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![],
-                success_reason: Some(CommitSuccessReason::ValidSignOff),
+                errors: vec![CommitError::InvalidAuthorEmail, CommitError::SignOffNotFound],
+                success_reason: None,
             }],
             config: Default::default(),
             head_ref: "main".to_string(),
-            num_commits_with_errors: 0,
-            only_last_commit_contains_errors: false,
+            num_commits_with_errors: 1,
+            only_last_commit_contains_errors: true,
         }
     );
 }
 
 #[test]
-fn single_commit_valid_signoff_email_contains_subdomain() {
+fn single_commit_invalid_author_email_also_used_in_signoff() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
-            email: "user1@email.some.test".to_string(),
+            email: "invalid".to_string(),
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user1".to_string(),
-            email: "user1@email.some.test".to_string(),
+            name: "user2".to_string(),
+            email: "user2@email.test".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1 <user1@email.some.test>
+            Signed-off-by: user1 <invalid>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -458,19 +1076,19 @@ fn single_commit_valid_signoff_email_contains_subdomain() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![],
-                success_reason: Some(CommitSuccessReason::ValidSignOff),
+                errors: vec![CommitError::InvalidAuthorEmail],
+                success_reason: None,
             }],
             config: Default::default(),
             head_ref: "main".to_string(),
-            num_commits_with_errors: 0,
-            only_last_commit_contains_errors: false,
+            num_commits_with_errors: 1,
+            only_last_commit_contains_errors: true,
         }
     );
 }
 
 #[test]
-fn single_commit_valid_signoff_github_bot_noreply_email() {
+fn single_commit_invalid_committer_email() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -478,24 +1096,26 @@ fn single_commit_valid_signoff_github_bot_noreply_email() {
             ..Default::default()
         }),
         committer: Some(User {
-            name: "github-actions[bot]".to_string(),
-            email: "12345678+github-actions[bot]@users.noreply.github.com".to_string(),
+            name: "user2".to_string(),
+            email: "invalid".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: github-actions[bot] <12345678+github-actions[bot]@users.noreply.github.com>
+            Signed-off-by: user1 <user1@email.test>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -504,44 +1124,89 @@ fn single_commit_valid_signoff_github_bot_noreply_email() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![],
-                success_reason: Some(CommitSuccessReason::ValidSignOff),
+                errors: vec![CommitError::InvalidCommitterEmail],
+                success_reason: None,
             }],
             config: Default::default(),
             head_ref: "main".to_string(),
-            num_commits_with_errors: 0,
-            only_last_commit_contains_errors: false,
+            num_commits_with_errors: 1,
+            only_last_commit_contains_errors: true,
         }
     );
 }
 
 #[test]
-fn single_commit_valid_signoff_email_contains_plus_alias() {
+fn single_commit_invalid_committer_email_and_no_signoff() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
-            email: "user1+alias@email.test".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
         committer: Some(User {
+            name: "user2".to_string(),
+            email: "invalid".to_string(),
+            ..Default::default()
+        }),
+        message: "Test commit message".to_string(),
+        ..Default::default()
+    };
+
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: Default::default(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![CommitError::InvalidCommitterEmail, CommitError::SignOffNotFound],
+                success_reason: None,
+            }],
+            config: Default::default(),
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 1,
+            only_last_commit_contains_errors: true,
+        }
+    );
+}
+
+#[test]
+fn single_commit_invalid_committer_email_also_used_in_signoff() {
+    let commit1 = Commit {
+        author: Some(User {
             name: "user1".to_string(),
-            email: "user1+alias@email.test".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer: Some(User {
+            name: "user2".to_string(),
+            email: "invalid".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1 <user1+alias@email.test>
+            Signed-off-by: user2 <invalid>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -550,19 +1215,19 @@ fn single_commit_valid_signoff_email_contains_plus_alias() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![],
-                success_reason: Some(CommitSuccessReason::ValidSignOff),
+                errors: vec![CommitError::InvalidCommitterEmail],
+                success_reason: None,
             }],
             config: Default::default(),
             head_ref: "main".to_string(),
-            num_commits_with_errors: 0,
-            only_last_commit_contains_errors: false,
+            num_commits_with_errors: 1,
+            only_last_commit_contains_errors: true,
         }
     );
 }
 
 #[test]
-fn single_commit_invalid_author_email() {
+fn single_commit_invalid_author_and_committer_email_same_email() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -570,24 +1235,26 @@ fn single_commit_invalid_author_email() {
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user2".to_string(),
-            email: "user2@email.test".to_string(),
+            name: "user1".to_string(),
+            email: "invalid".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user2 <user2@email.test>
+            Signed-off-by: user1 <user1@email.test>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -596,7 +1263,7 @@ fn single_commit_invalid_author_email() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::InvalidAuthorEmail],
+                errors: vec![CommitError::InvalidCommitterEmail],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -608,7 +1275,7 @@ fn single_commit_invalid_author_email() {
 }
 
 #[test]
-fn single_commit_invalid_author_email_and_no_signoff() {
+fn single_commit_invalid_author_and_committer_email_different_emails() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -616,8 +1283,59 @@ fn single_commit_invalid_author_email_and_no_signoff() {
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user2".to_string(),
-            email: "user2@email.test".to_string(),
+            name: "user1".to_string(),
+            email: "invalid2".to_string(),
+            ..Default::default()
+        }),
+        message: indoc! {r"
+            Test commit message
+
+            Signed-off-by: user1 <user1@email.test>
+        "}
+        .to_string(),
+        ..Default::default()
+    };
+
+    let input = CheckInput {
+        cherry_picked_commits: vec![],
+        commits: vec![commit1.clone()],
+        config: Default::default(),
+        head_ref: "main".to_string(),
+        members: vec![],
+        pull_request_description: None,
+    };
+    let output = check(&input);
+
+    assert_eq!(
+        output,
+        CheckOutput {
+            commits: vec![CommitCheckOutput {
+                commit: commit1,
+                errors: vec![
+                    CommitError::InvalidCommitterEmail,
+                    CommitError::InvalidAuthorEmail
+                ],
+                success_reason: None,
+            }],
+            config: Default::default(),
+            head_ref: "main".to_string(),
+            num_commits_with_errors: 1,
+            only_last_commit_contains_errors: true,
+        }
+    );
+}
+
+#[test]
+fn single_commit_signoff_not_found() {
+    let commit1 = Commit {
+        author: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
+            ..Default::default()
+        }),
+        committer: Some(User {
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
         message: "Test commit message".to_string(),
@@ -625,10 +1343,12 @@ fn single_commit_invalid_author_email_and_no_signoff() {
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -637,7 +1357,7 @@ fn single_commit_invalid_author_email_and_no_signoff() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::InvalidAuthorEmail, CommitError::SignOffNotFound],
+                errors: vec![CommitError::SignOffNotFound],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -649,32 +1369,36 @@ fn single_commit_invalid_author_email_and_no_signoff() {
 }
 
 #[test]
-fn single_commit_invalid_author_email_also_used_in_signoff() {
+fn single_commit_invalid_signoff_multiple_signoffs() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
-            email: "invalid".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user2".to_string(),
-            email: "user2@email.test".to_string(),
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1 <invalid>
+            Signed-off-by: userx <userx@email.test>
+            Signed-off-by: usery <usery@email.test>
+            Signed-off-by: userz <userz@email.test>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -683,7 +1407,7 @@ fn single_commit_invalid_author_email_also_used_in_signoff() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::InvalidAuthorEmail],
+                errors: vec![CommitError::SignOffMismatch],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -695,7 +1419,7 @@ fn single_commit_invalid_author_email_also_used_in_signoff() {
 }
 
 #[test]
-fn single_commit_invalid_committer_email() {
+fn single_commit_invalid_signoff_name_mismatch() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -703,24 +1427,26 @@ fn single_commit_invalid_committer_email() {
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user2".to_string(),
-            email: "invalid".to_string(),
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1 <user1@email.test>
+            Signed-off-by: user1x <user1@email.test>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -729,7 +1455,7 @@ fn single_commit_invalid_committer_email() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::InvalidCommitterEmail],
+                errors: vec![CommitError::SignOffMismatch],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -741,7 +1467,7 @@ fn single_commit_invalid_committer_email() {
 }
 
 #[test]
-fn single_commit_invalid_committer_email_and_no_signoff() {
+fn single_commit_invalid_signoff_email_mismatch() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -749,19 +1475,26 @@ fn single_commit_invalid_committer_email_and_no_signoff() {
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user2".to_string(),
-            email: "invalid".to_string(),
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
-        message: "Test commit message".to_string(),
+        message: indoc! {r"
+            Test commit message
+
+            Signed-off-by: user1 <user1x@email.test>
+        "}
+        .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -770,7 +1503,7 @@ fn single_commit_invalid_committer_email_and_no_signoff() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::InvalidCommitterEmail, CommitError::SignOffNotFound],
+                errors: vec![CommitError::SignOffMismatch],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -782,7 +1515,7 @@ fn single_commit_invalid_committer_email_and_no_signoff() {
 }
 
 #[test]
-fn single_commit_invalid_committer_email_also_used_in_signoff() {
+fn single_commit_invalid_signoff_name_and_email_mismatch() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -790,24 +1523,26 @@ fn single_commit_invalid_committer_email_also_used_in_signoff() {
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user2".to_string(),
-            email: "invalid".to_string(),
+            name: "user1".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user2 <invalid>
+            Signed-off-by: user1x <user1x@email.test>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -816,7 +1551,7 @@ fn single_commit_invalid_committer_email_also_used_in_signoff() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::InvalidCommitterEmail],
+                errors: vec![CommitError::SignOffMismatch],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -828,32 +1563,34 @@ fn single_commit_invalid_committer_email_also_used_in_signoff() {
 }
 
 #[test]
-fn single_commit_invalid_author_and_committer_email_same_email() {
+fn single_commit_invalid_signoff_extra_whitespace_around_name() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
-            email: "invalid".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
         committer: Some(User {
             name: "user1".to_string(),
-            email: "invalid".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1 <user1@email.test>
+            Signed-off-by:  user1  <user1@email.test>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -862,7 +1599,7 @@ fn single_commit_invalid_author_and_committer_email_same_email() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::InvalidCommitterEmail],
+                errors: vec![CommitError::SignOffMismatch],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -874,32 +1611,34 @@ fn single_commit_invalid_author_and_committer_email_same_email() {
 }
 
 #[test]
-fn single_commit_invalid_author_and_committer_email_different_emails() {
+fn single_commit_invalid_signoff_extra_whitespace_around_email() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
-            email: "invalid".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
         committer: Some(User {
             name: "user1".to_string(),
-            email: "invalid2".to_string(),
+            email: "user1@email.test".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1 <user1@email.test>
+            Signed-off-by: user1 < user1@email.test >
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -908,10 +1647,7 @@ fn single_commit_invalid_author_and_committer_email_different_emails() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![
-                    CommitError::InvalidCommitterEmail,
-                    CommitError::InvalidAuthorEmail
-                ],
+                errors: vec![CommitError::SignOffMismatch],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -923,7 +1659,7 @@ fn single_commit_invalid_author_and_committer_email_different_emails() {
 }
 
 #[test]
-fn single_commit_signoff_not_found() {
+fn single_commit_invalid_signoff_missing_name_in_signoff() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -935,15 +1671,22 @@ fn single_commit_signoff_not_found() {
             email: "user1@email.test".to_string(),
             ..Default::default()
         }),
-        message: "Test commit message".to_string(),
+        message: indoc! {r"
+            Test commit message
+
+            Signed-off-by: <user1@email.test>
+        "}
+        .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -964,7 +1707,7 @@ fn single_commit_signoff_not_found() {
 }
 
 #[test]
-fn single_commit_invalid_signoff_multiple_signoffs() {
+fn single_commit_invalid_signoff_missing_email_in_signoff() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -979,19 +1722,19 @@ fn single_commit_invalid_signoff_multiple_signoffs() {
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: userx <userx@email.test>
-            Signed-off-by: usery <usery@email.test>
-            Signed-off-by: userz <userz@email.test>
+            Signed-off-by: user1
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1000,7 +1743,7 @@ fn single_commit_invalid_signoff_multiple_signoffs() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::SignOffMismatch],
+                errors: vec![CommitError::SignOffNotFound],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -1012,7 +1755,7 @@ fn single_commit_invalid_signoff_multiple_signoffs() {
 }
 
 #[test]
-fn single_commit_invalid_signoff_name_mismatch() {
+fn single_commit_invalid_signoff_missing_email_brackets_in_signoff() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1027,17 +1770,19 @@ fn single_commit_invalid_signoff_name_mismatch() {
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1x <user1@email.test>
+            Signed-off-by: user1 user1@email.test
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1046,7 +1791,7 @@ fn single_commit_invalid_signoff_name_mismatch() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::SignOffMismatch],
+                errors: vec![CommitError::SignOffNotFound],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -1058,7 +1803,7 @@ fn single_commit_invalid_signoff_name_mismatch() {
 }
 
 #[test]
-fn single_commit_invalid_signoff_email_mismatch() {
+fn single_commit_invalid_signoff_missing_name_and_email_in_signoff() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1073,17 +1818,19 @@ fn single_commit_invalid_signoff_email_mismatch() {
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1 <user1x@email.test>
+            Signed-off-by:
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1092,7 +1839,7 @@ fn single_commit_invalid_signoff_email_mismatch() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::SignOffMismatch],
+                errors: vec![CommitError::SignOffNotFound],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -1104,7 +1851,7 @@ fn single_commit_invalid_signoff_email_mismatch() {
 }
 
 #[test]
-fn single_commit_invalid_signoff_name_and_email_mismatch() {
+fn single_commit_invalid_signoff_name_and_email_swapped_in_signoff() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1119,17 +1866,19 @@ fn single_commit_invalid_signoff_name_and_email_mismatch() {
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1x <user1x@email.test>
+            Signed-off-by: user1@email.test <user1>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1150,7 +1899,7 @@ fn single_commit_invalid_signoff_name_and_email_mismatch() {
 }
 
 #[test]
-fn single_commit_invalid_signoff_extra_whitespace_around_name() {
+fn single_commit_invalid_signoff_invalid_email_in_signoff() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1165,17 +1914,19 @@ fn single_commit_invalid_signoff_extra_whitespace_around_name() {
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by:  user1  <user1@email.test>
+            Signed-off-by: user1 <user1(at)email.test>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1196,7 +1947,7 @@ fn single_commit_invalid_signoff_extra_whitespace_around_name() {
 }
 
 #[test]
-fn single_commit_invalid_signoff_extra_whitespace_around_email() {
+fn single_commit_invalid_signoff_email_alias_used_in_signoff_but_not_in_author_email() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1211,17 +1962,19 @@ fn single_commit_invalid_signoff_extra_whitespace_around_email() {
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1 < user1@email.test >
+            Signed-off-by: user1 <user1+alias@email.test>
         "}
         .to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1242,7 +1995,7 @@ fn single_commit_invalid_signoff_extra_whitespace_around_email() {
 }
 
 #[test]
-fn single_commit_invalid_signoff_missing_name_in_signoff() {
+fn single_commit_no_signoff_valid_signoff_in_cherry_picked_commit() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1250,6 +2003,20 @@ fn single_commit_invalid_signoff_missing_name_in_signoff() {
             ..Default::default()
         }),
         committer: Some(User {
+            name: "user2".to_string(),
+            email: "user2@email.test".to_string(),
+            ..Default::default()
+        }),
+        message: indoc! {r"
+            Test commit message
+
+            (cherry picked from commit 0123456789abcdef0123456789abcdef01234567)
+        "}
+        .to_string(),
+        ..Default::default()
+    };
+    let original_commit1 = Commit {
+        author: Some(User {
             name: "user1".to_string(),
             email: "user1@email.test".to_string(),
             ..Default::default()
@@ -1257,17 +2024,24 @@ fn single_commit_invalid_signoff_missing_name_in_signoff() {
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: <user1@email.test>
+            Signed-off-by: user1 <user1@email.test>
         "}
         .to_string(),
+        sha: "0123456789abcdef0123456789abcdef01234567".to_string(),
         ..Default::default()
     };
 
+    let config = Config {
+        allow_cherry_picks: Some(true),
+        ..Default::default()
+    };
     let input = CheckInput {
+        cherry_picked_commits: vec![original_commit1],
         commits: vec![commit1.clone()],
-        config: Default::default(),
+        config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1276,19 +2050,19 @@ fn single_commit_invalid_signoff_missing_name_in_signoff() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::SignOffNotFound],
-                success_reason: None,
+                errors: vec![],
+                success_reason: Some(CommitSuccessReason::ValidSignOffInCherryPickedCommit),
             }],
-            config: Default::default(),
+            config,
             head_ref: "main".to_string(),
-            num_commits_with_errors: 1,
-            only_last_commit_contains_errors: true,
+            num_commits_with_errors: 0,
+            only_last_commit_contains_errors: false,
         }
     );
 }
 
 #[test]
-fn single_commit_invalid_signoff_missing_email_in_signoff() {
+fn single_commit_no_signoff_valid_signoff_in_cherry_picked_commit_but_cherry_picks_not_allowed_in_config() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1296,6 +2070,20 @@ fn single_commit_invalid_signoff_missing_email_in_signoff() {
             ..Default::default()
         }),
         committer: Some(User {
+            name: "user2".to_string(),
+            email: "user2@email.test".to_string(),
+            ..Default::default()
+        }),
+        message: indoc! {r"
+            Test commit message
+
+            (cherry picked from commit 0123456789abcdef0123456789abcdef01234567)
+        "}
+        .to_string(),
+        ..Default::default()
+    };
+    let original_commit1 = Commit {
+        author: Some(User {
             name: "user1".to_string(),
             email: "user1@email.test".to_string(),
             ..Default::default()
@@ -1303,17 +2091,20 @@ fn single_commit_invalid_signoff_missing_email_in_signoff() {
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1
+            Signed-off-by: user1 <user1@email.test>
         "}
         .to_string(),
+        sha: "0123456789abcdef0123456789abcdef01234567".to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![original_commit1],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1334,7 +2125,7 @@ fn single_commit_invalid_signoff_missing_email_in_signoff() {
 }
 
 #[test]
-fn single_commit_invalid_signoff_missing_email_brackets_in_signoff() {
+fn single_commit_no_signoff_valid_signoff_in_cherry_picked_commit_from_another_author() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1342,24 +2133,45 @@ fn single_commit_invalid_signoff_missing_email_brackets_in_signoff() {
             ..Default::default()
         }),
         committer: Some(User {
-            name: "user1".to_string(),
-            email: "user1@email.test".to_string(),
+            name: "user2".to_string(),
+            email: "user2@email.test".to_string(),
             ..Default::default()
         }),
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by: user1 user1@email.test
+            (cherry picked from commit 0123456789abcdef0123456789abcdef01234567)
+        "}
+        .to_string(),
+        ..Default::default()
+    };
+    let original_commit1 = Commit {
+        author: Some(User {
+            name: "userx".to_string(),
+            email: "userx@email.test".to_string(),
+            ..Default::default()
+        }),
+        message: indoc! {r"
+            Test commit message
+
+            Signed-off-by: userx <userx@email.test>
         "}
         .to_string(),
+        sha: "0123456789abcdef0123456789abcdef01234567".to_string(),
         ..Default::default()
     };
 
+    let config = Config {
+        allow_cherry_picks: Some(true),
+        ..Default::default()
+    };
     let input = CheckInput {
+        cherry_picked_commits: vec![original_commit1],
         commits: vec![commit1.clone()],
-        config: Default::default(),
+        config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1371,7 +2183,7 @@ fn single_commit_invalid_signoff_missing_email_brackets_in_signoff() {
                 errors: vec![CommitError::SignOffNotFound],
                 success_reason: None,
             }],
-            config: Default::default(),
+            config,
             head_ref: "main".to_string(),
             num_commits_with_errors: 1,
             only_last_commit_contains_errors: true,
@@ -1380,7 +2192,7 @@ fn single_commit_invalid_signoff_missing_email_brackets_in_signoff() {
 }
 
 #[test]
-fn single_commit_invalid_signoff_missing_name_and_email_in_signoff() {
+fn single_commit_no_signoff_signoff_mismatch_in_cherry_picked_commit() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1388,6 +2200,20 @@ fn single_commit_invalid_signoff_missing_name_and_email_in_signoff() {
             ..Default::default()
         }),
         committer: Some(User {
+            name: "user2".to_string(),
+            email: "user2@email.test".to_string(),
+            ..Default::default()
+        }),
+        message: indoc! {r"
+            Test commit message
+
+            (cherry picked from commit 0123456789abcdef0123456789abcdef01234567)
+        "}
+        .to_string(),
+        ..Default::default()
+    };
+    let original_commit1 = Commit {
+        author: Some(User {
             name: "user1".to_string(),
             email: "user1@email.test".to_string(),
             ..Default::default()
@@ -1395,17 +2221,24 @@ fn single_commit_invalid_signoff_missing_name_and_email_in_signoff() {
         message: indoc! {r"
             Test commit message
 
-            Signed-off-by:
+            Signed-off-by: userx <userx@email.test>
         "}
         .to_string(),
+        sha: "0123456789abcdef0123456789abcdef01234567".to_string(),
         ..Default::default()
     };
 
+    let config = Config {
+        allow_cherry_picks: Some(true),
+        ..Default::default()
+    };
     let input = CheckInput {
+        cherry_picked_commits: vec![original_commit1],
         commits: vec![commit1.clone()],
-        config: Default::default(),
+        config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1417,7 +2250,7 @@ fn single_commit_invalid_signoff_missing_name_and_email_in_signoff() {
                 errors: vec![CommitError::SignOffNotFound],
                 success_reason: None,
             }],
-            config: Default::default(),
+            config,
             head_ref: "main".to_string(),
             num_commits_with_errors: 1,
             only_last_commit_contains_errors: true,
@@ -1426,7 +2259,7 @@ fn single_commit_invalid_signoff_missing_name_and_email_in_signoff() {
 }
 
 #[test]
-fn single_commit_invalid_signoff_name_and_email_swapped_in_signoff() {
+fn single_commit_no_signoff_valid_signoff_in_pull_request_description() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1438,20 +2271,28 @@ fn single_commit_invalid_signoff_name_and_email_swapped_in_signoff() {
             email: "user1@email.test".to_string(),
             ..Default::default()
         }),
-        message: indoc! {r"
-            Test commit message
-
-            Signed-off-by: user1@email.test <user1>
-        "}
-        .to_string(),
+        message: "Test commit message".to_string(),
         ..Default::default()
     };
 
+    let config = Config {
+        squash_merge: Some(true),
+        ..Default::default()
+    };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
-        config: Default::default(),
+        config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: Some(
+            indoc! {r"
+                Test pull request
+
+                Signed-off-by: user1 <user1@email.test>
+            "}
+            .to_string(),
+        ),
     };
     let output = check(&input);
 
@@ -1460,19 +2301,20 @@ fn single_commit_invalid_signoff_name_and_email_swapped_in_signoff() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::SignOffMismatch],
-                success_reason: None,
+                errors: vec![],
+                success_reason: Some(CommitSuccessReason::ValidSignOffInPullRequestDescription),
             }],
-            config: Default::default(),
+            config,
             head_ref: "main".to_string(),
-            num_commits_with_errors: 1,
-            only_last_commit_contains_errors: true,
+            num_commits_with_errors: 0,
+            only_last_commit_contains_errors: false,
         }
     );
 }
 
 #[test]
-fn single_commit_invalid_signoff_invalid_email_in_signoff() {
+fn single_commit_no_signoff_valid_signoff_in_pull_request_description_but_squash_merge_not_enabled_in_config()
+{
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1484,20 +2326,24 @@ fn single_commit_invalid_signoff_invalid_email_in_signoff() {
             email: "user1@email.test".to_string(),
             ..Default::default()
         }),
-        message: indoc! {r"
-            Test commit message
-
-            Signed-off-by: user1 <user1(at)email.test>
-        "}
-        .to_string(),
+        message: "Test commit message".to_string(),
         ..Default::default()
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: Some(
+            indoc! {r"
+                Test pull request
+
+                Signed-off-by: user1 <user1@email.test>
+            "}
+            .to_string(),
+        ),
     };
     let output = check(&input);
 
@@ -1506,7 +2352,7 @@ fn single_commit_invalid_signoff_invalid_email_in_signoff() {
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::SignOffMismatch],
+                errors: vec![CommitError::SignOffNotFound],
                 success_reason: None,
             }],
             config: Default::default(),
@@ -1518,7 +2364,7 @@ fn single_commit_invalid_signoff_invalid_email_in_signoff() {
 }
 
 #[test]
-fn single_commit_invalid_signoff_email_alias_used_in_signoff_but_not_in_author_email() {
+fn single_commit_no_signoff_invalid_signoff_in_pull_request_description() {
     let commit1 = Commit {
         author: Some(User {
             name: "user1".to_string(),
@@ -1530,20 +2376,28 @@ fn single_commit_invalid_signoff_email_alias_used_in_signoff_but_not_in_author_e
             email: "user1@email.test".to_string(),
             ..Default::default()
         }),
-        message: indoc! {r"
-            Test commit message
-
-            Signed-off-by: user1 <user1+alias@email.test>
-        "}
-        .to_string(),
+        message: "Test commit message".to_string(),
         ..Default::default()
     };
 
+    let config = Config {
+        squash_merge: Some(true),
+        ..Default::default()
+    };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone()],
-        config: Default::default(),
+        config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: Some(
+            indoc! {r"
+                Test pull request
+
+                Signed-off-by: userx <userx@email.test>
+            "}
+            .to_string(),
+        ),
     };
     let output = check(&input);
 
@@ -1552,10 +2406,10 @@ fn single_commit_invalid_signoff_email_alias_used_in_signoff_but_not_in_author_e
         CheckOutput {
             commits: vec![CommitCheckOutput {
                 commit: commit1,
-                errors: vec![CommitError::SignOffMismatch],
+                errors: vec![CommitError::SignOffNotFound],
                 success_reason: None,
             }],
-            config: Default::default(),
+            config,
             head_ref: "main".to_string(),
             num_commits_with_errors: 1,
             only_last_commit_contains_errors: true,
@@ -1587,10 +2441,12 @@ fn two_commits_valid_signoff_in_both() {
     let commit2 = commit1.clone();
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1654,10 +2510,12 @@ fn two_commits_no_signoff_in_first_valid_signoff_in_second() {
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1721,10 +2579,12 @@ fn two_commits_valid_signoff_in_first_no_signoff_in_second() {
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1793,10 +2653,12 @@ fn two_commits_invalid_signoff_in_first_valid_signoff_in_second() {
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1865,10 +2727,12 @@ fn two_commits_valid_signoff_in_first_invalid_signoff_in_second() {
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1932,10 +2796,12 @@ fn two_commits_no_signoff_in_first_invalid_signoff_in_second() {
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1999,10 +2865,12 @@ fn two_commits_invalid_signoff_in_first_no_signoff_in_second() {
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2070,10 +2938,12 @@ fn two_commits_no_signoff_in_first_valid_remediation_commit_in_second_but_remedi
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2147,10 +3017,12 @@ fn two_commits_no_signoff_in_first_valid_remediation_commit_matching_author_in_s
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2224,10 +3096,12 @@ fn two_commits_no_signoff_in_first_valid_remediation_commit_matching_committer_i
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2306,10 +3180,12 @@ fn two_commits_invalid_signoff_incorrect_name_in_first_valid_remediation_commit_
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2388,10 +3264,12 @@ fn two_commits_invalid_signoff_incorrect_email_in_first_valid_remediation_commit
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2470,10 +3348,12 @@ fn two_commits_valid_signoff_in_first_redundant_remediation_commit_in_second() {
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2552,10 +3432,12 @@ fn two_commits_valid_signoff_in_first_remediation_commit_non_existent_sha_in_sec
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2627,10 +3509,12 @@ fn two_commits_no_signoff_in_first_remediation_commit_no_signoff_in_second() {
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2704,10 +3588,12 @@ fn two_commits_no_signoff_in_first_remediation_commit_different_name_in_second()
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2781,10 +3667,12 @@ fn two_commits_no_signoff_in_first_remediation_commit_different_email_in_second(
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2858,10 +3746,12 @@ fn two_commits_no_signoff_in_first_remediation_commit_different_name_and_email_i
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -2935,10 +3825,12 @@ fn two_commits_no_signoff_in_first_remediation_commit_different_name_in_signoff_
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3012,10 +3904,12 @@ fn two_commits_no_signoff_in_first_remediation_commit_different_email_in_signoff
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3089,10 +3983,12 @@ fn two_commits_no_signoff_in_first_remediation_commit_different_name_and_email_i
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3166,10 +4062,12 @@ fn two_commits_no_signoff_in_first_remediation_commit_different_name_in_remediat
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3243,10 +4141,12 @@ fn two_commits_no_signoff_in_first_remediation_commit_different_email_in_remedia
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3320,10 +4220,12 @@ fn two_commits_no_signoff_in_first_remediation_commit_different_name_and_email_i
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3397,10 +4299,12 @@ fn two_commits_no_signoff_in_first_remediation_commit_sha_mismatch_in_second() {
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3468,10 +4372,12 @@ fn two_commits_no_signoff_in_first_3p_valid_remediation_commit_in_second_but_rem
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3546,10 +4452,12 @@ fn two_commits_no_signoff_in_first_3p_valid_remediation_commit_in_second_but_3p_
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3623,10 +4531,12 @@ fn two_commits_no_signoff_in_first_3p_valid_remediation_commit_from_same_author_
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3701,10 +4611,12 @@ fn two_commits_no_signoff_in_first_3p_valid_remediation_commit_from_different_au
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3778,10 +4690,12 @@ fn two_commits_no_signoff_in_first_3p_valid_remediation_commit_from_committer_in
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3855,10 +4769,12 @@ fn two_commits_no_signoff_in_first_3p_valid_remediation_commit_in_second_individ
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -3932,10 +4848,12 @@ fn two_commits_no_signoff_in_first_3p_remediation_commit_declarant_name_mismatch
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4009,10 +4927,12 @@ fn two_commits_no_signoff_in_first_3p_remediation_commit_declarant_email_mismatc
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4086,10 +5006,12 @@ fn two_commits_no_signoff_in_first_3p_remediation_commit_sha_mismatch_in_second(
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4163,10 +5085,12 @@ fn two_commits_no_signoff_in_first_invalid_3p_remediation_commit_in_second() {
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4240,10 +5164,12 @@ fn two_commits_no_signoff_in_first_3p_remediation_commit_representative_name_mis
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4317,10 +5243,12 @@ fn two_commits_no_signoff_in_first_3p_remediation_commit_representative_email_mi
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4392,10 +5320,12 @@ fn two_commits_no_signoff_in_first_3p_remediation_commit_no_signoff_in_second()
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4447,10 +5377,12 @@ fn three_commits_valid_signoff_in_all() {
     let commit3 = commit1.clone();
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4520,10 +5452,12 @@ fn three_commits_valid_signoff_first_and_second_no_signoff_third() {
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4611,10 +5545,12 @@ fn three_commits_invalid_signoff_first_no_signoff_second_valid_signoff_third() {
     };
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4689,10 +5625,12 @@ fn three_commits_valid_signoff_first_invalid_signoff_second_valid_signoff_third(
     let commit3 = commit1.clone();
 
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: Default::default(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4792,10 +5730,12 @@ fn three_commits_no_signoff_in_first_remediation_commit_without_signoff_in_secon
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -4890,10 +5830,12 @@ fn three_commits_no_signoff_in_first_no_signoff_in_second_valid_remediation_comm
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -5000,10 +5942,12 @@ fn three_commits_valid_signoff_in_first_redundant_remediation_commit_in_second_r
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -5105,10 +6049,12 @@ fn three_commits_no_signoff_in_first_valid_remediation_commit_in_second_redundan
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -5208,10 +6154,12 @@ fn three_commits_no_signoff_in_first_remediation_commit_no_signoff_in_second_val
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -5311,10 +6259,12 @@ fn three_commits_no_signoff_in_first_3p_remediation_commit_no_signoff_in_second_
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -5414,10 +6364,12 @@ fn three_commits_no_signoff_in_first_3p_remediation_commit_no_signoff_in_second_
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -5515,10 +6467,12 @@ fn three_commits_no_signoff_in_first_3p_remediation_commit_no_signoff_in_second_
         ..Default::default()
     };
     let input = CheckInput {
+        cherry_picked_commits: vec![],
         commits: vec![commit1.clone(), commit2.clone(), commit3.clone()],
         config: config.clone(),
         head_ref: "main".to_string(),
         members: vec![],
+        pull_request_description: None,
     };
     let output = check(&input);
 
@@ -1,17 +1,33 @@
 //! This module contains the logic to process GitHub webhook events.
 
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use askama::Template;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+use tokio::task::JoinSet;
+use tracing::warn;
 
 use crate::{
-    dco::check::{CheckInput, check},
+    dco::check::{
+        CheckExplanation, CheckFailureComment, CheckInput, CheckOutput, ConfigPreview,
+        FAILURE_COMMENT_MARKER, MergeGroupCheckOutput, PushCheckOutput, PushIssue, check,
+        get_cherry_picked_shas,
+    },
     github::{
-        CheckRun, CheckRunAction, CheckRunConclusion, CheckRunEvent, CheckRunEventAction, CheckRunStatus,
-        Commit, DynGHClient, Event, MergeGroupEvent, MergeGroupEventAction, NewCheckRunInput,
-        PullRequestEvent, PullRequestEventAction,
+        CONFIG_FILE_PATHS, CheckRun, CheckRunAction, CheckRunConclusion, CheckRunEvent, CheckRunEventAction,
+        CheckRunEventCheckRun, CheckRunStatus, Commit, CommitStatus, CommitStatusState, Comparison, Config,
+        ConfigError, ConfigSource, Ctx, DynGHClient, Event, InstallationDetails, InstallationEvent,
+        InstallationEventAction, InstallationRepositoriesEvent, InstallationRepositoriesEventAction,
+        InstallationRepository, IssueCommentEvent, IssueCommentEventAction, Label, MergeGroupEvent,
+        MergeGroupEventAction, NewCheckRunInput, NewCommitStatusInput, OrganizationEvent,
+        OrganizationEventAction, PullRequest, PullRequestEvent, PullRequestEventAction,
+        PullRequestEventChanges, PushEvent, RepositoryPermission,
     },
 };
 
@@ -24,12 +40,53 @@ const CHECK_NAME: &str = "DCO";
 /// Title of the check run when the check fails.
 const CHECK_FAILED_TITLE: &str = "Check failed";
 
+/// Title of the check run when the check fails in advisory mode.
+const CHECK_FAILED_ADVISORY_TITLE: &str = "Check failed (advisory mode)";
+
 /// Title of the check run when the check passes.
 const CHECK_PASSED_TITLE: &str = "Check passed!";
 
+/// Title of the check run when the check is deferred on a draft pull request.
+const CHECK_DEFERRED_DRAFT_TITLE: &str = "Check deferred: pull request is a draft";
+
+/// Summary of the check run when the check is deferred on a draft pull request.
+const CHECK_DEFERRED_DRAFT_SUMMARY: &str = "This pull request is a draft, so the DCO check has been deferred. The commits will be checked once the pull request is marked as ready for review.";
+
+/// Title of the check run while the check is in progress.
+const CHECK_IN_PROGRESS_TITLE: &str = "Checking commits...";
+
+/// Summary of the check run while the check is in progress.
+const CHECK_IN_PROGRESS_SUMMARY: &str =
+    "The commits are being checked, the result will be available shortly.";
+
+/// Title of the check run when an internal error occurs processing an event.
+const CHECK_INTERNAL_ERROR_TITLE: &str = "Check failed: internal error";
+
+/// Title of the check run when the repository configuration is not valid.
+const CHECK_INVALID_CONFIG_TITLE: &str = "Check failed: invalid configuration";
+
+/// Title of the check run when the pull request is exempted by a label.
+const CHECK_EXEMPTED_LABEL_TITLE: &str = "Check passed: exempted by label";
+
+/// Title of the check run when the check is not enforced on the base branch.
+const CHECK_SKIPPED_BRANCH_TITLE: &str = "Check skipped: base branch not enforced";
+
+/// Minimum permission the user applying the exemption label must have.
+const EXEMPTION_LABEL_MIN_PERMISSION: RepositoryPermission = RepositoryPermission::Maintain;
+
+/// Color of the failure label (used when creating it).
+const FAILURE_LABEL_COLOR: &str = "d73a4a";
+
+/// Description of the failure label (used when creating it).
+const FAILURE_LABEL_DESCRIPTION: &str = "Some commits are not correctly signed off (DCO)";
+
 /// Summary of the check when requested by a merge group.
 const MERGE_GROUP_CHECKS_REQUESTED_SUMMARY: &str = "Check result set to passed for the merge group";
 
+/// Prefix of the title of the issue opened when the commits pushed directly to
+/// a branch do not pass the check (followed by the branch name).
+const PUSH_ISSUE_TITLE_PREFIX: &str = "DCO check failed for commits pushed to";
+
 /// Identifier of the override action (set check result to passed).
 const OVERRIDE_ACTION_IDENTIFIER: &str = "override";
 
@@ -42,15 +99,159 @@ const OVERRIDE_ACTION_DESCRIPTION: &str = "Manually set DCO check result to pass
 /// Summary of the override action.
 const OVERRIDE_ACTION_SUMMARY: &str = "Check result was manually set to passed";
 
+/// Minimum permission on the repository required to override the check using
+/// the override command (when the override is not restricted in the config).
+const OVERRIDE_COMMAND_MIN_PERMISSION: RepositoryPermission = RepositoryPermission::Write;
+
+/// Marker used to locate the warning added to the check summary when a user
+/// not allowed to override the check uses the override action.
+const OVERRIDE_NOT_ALLOWED_MARKER: &str = "<!-- dco2-override-not-allowed -->";
+
+/// Maximum number of pull requests checked concurrently when all the open
+/// pull requests in a repository are checked at once.
+const OPEN_PULL_REQUESTS_CHECK_CONCURRENCY: usize = 5;
+
+/// Maximum number of pull requests checked when all the open pull requests in
+/// a repository are checked at once. The remaining ones will be checked the
+/// next time they are updated (or when a recheck is requested).
+const OPEN_PULL_REQUESTS_CHECK_LIMIT: usize = 20;
+
+/// Prefix of the commands that can be used in pull requests comments.
+const COMMAND_PREFIX: &str = "/dco";
+
+/// Minimum time between rate limited commands on the same pull request.
+const COMMANDS_RATE_LIMIT_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Process the GitHub webhook event provided, taking the appropriate action.
 pub async fn process_event(gh_client: DynGHClient, event: &Event) -> Result<()> {
     match event {
         Event::CheckRun(event) => process_check_run_event(gh_client, event).await,
+        Event::Installation(event) => process_installation_event(gh_client, event).await,
+        Event::InstallationRepositories(event) => {
+            process_installation_repositories_event(gh_client, event).await
+        }
+        Event::IssueComment(event) => process_issue_comment_event(gh_client, event).await,
         Event::MergeGroup(event) => process_merge_group_event(gh_client, event).await,
+        Event::Organization(event) => process_organization_event(gh_client, event).await,
         Event::PullRequest(event) => process_pull_request_event(gh_client, event).await,
+        Event::Push(event) => process_push_event(gh_client, event).await,
     }
 }
 
+/// Report an error that occurred while processing the event provided,
+/// publishing a failed check result so that the error is visible in GitHub.
+/// The event delivery id (if provided) is included so that the error can be
+/// correlated with the logs. When the check was in progress, its check run is
+/// updated with the error. Errors that occurred once the check result was
+/// published are not reported, as they would replace the actual result.
+pub async fn report_error(
+    gh_client: DynGHClient,
+    event: &Event,
+    event_id: Option<&str>,
+    err: &anyhow::Error,
+) -> Result<()> {
+    let started_at = Utc::now();
+
+    // Skip errors that occurred after the check result was published
+    if err.downcast_ref::<CheckResultPublished>().is_some() {
+        return Ok(());
+    }
+
+    // Complete the check run in progress (if any) as failed. This applies to
+    // any event that starts a check, including the ones not related to a
+    // single pull request (i.e. rechecks, pushes or open pull requests checks)
+    if let Some(in_progress) = err.downcast_ref::<CheckInProgress>() {
+        return complete_check_in_progress(gh_client, in_progress, event_id, started_at).await;
+    }
+
+    // Get the context and the head commit the check result will be published
+    // for. Issue comments events do not include it, and installation,
+    // organization and push events are not related to a single pull request,
+    // so no check result is published for them
+    let (ctx, head_sha) = match event {
+        Event::CheckRun(event) => (event.ctx(), event.check_run.head_sha.clone()),
+        Event::Installation(_)
+        | Event::InstallationRepositories(_)
+        | Event::IssueComment(_)
+        | Event::Organization(_)
+        | Event::Push(_) => return Ok(()),
+        Event::MergeGroup(event) => (event.ctx(), event.merge_group.head_commit.id.clone()),
+        Event::PullRequest(event) => (event.ctx(), event.pull_request.head.sha.clone()),
+    };
+
+    // The error occurred before the check started, so we try to get the
+    // repository configuration, falling back to the default one if it is not
+    // available
+    let config = gh_client.get_config(&ctx).await.ok().flatten().unwrap_or_default();
+    publish_internal_error(gh_client, &ctx, &config, head_sha, None, event_id, started_at).await
+}
+
+/// Complete the check run in progress provided as failed due to an internal
+/// error.
+async fn complete_check_in_progress(
+    gh_client: DynGHClient,
+    in_progress: &CheckInProgress,
+    event_id: Option<&str>,
+    started_at: DateTime<Utc>,
+) -> Result<()> {
+    publish_internal_error(
+        gh_client,
+        &in_progress.ctx,
+        &in_progress.config,
+        in_progress.head_sha.clone(),
+        in_progress.check_run_id,
+        event_id,
+        started_at,
+    )
+    .await
+}
+
+/// Publish a failed check result describing an internal error.
+async fn publish_internal_error(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    config: &Config,
+    head_sha: String,
+    check_run_id: Option<i64>,
+    event_id: Option<&str>,
+    started_at: DateTime<Utc>,
+) -> Result<()> {
+    let mut summary = "An internal error occurred while running the DCO check. The check will be retried the next time the pull request is updated, or it can be requested again by commenting `/dco recheck` in the pull request.".to_string();
+    if let Some(event_id) = event_id {
+        summary.push_str(&format!("\n\nEvent delivery id: `{event_id}`"));
+    }
+    let check_run = CheckRun::new(NewCheckRunInput {
+        actions: vec![],
+        completed_at: Some(Utc::now()),
+        conclusion: Some(CheckRunConclusion::Failure),
+        head_sha,
+        name: CHECK_NAME.to_string(),
+        started_at,
+        status: CheckRunStatus::Completed,
+        summary,
+        title: CHECK_INTERNAL_ERROR_TITLE.to_string(),
+    });
+    publish_check_result(gh_client, ctx, config, &check_run, check_run_id, None).await
+}
+
+/// Marker attached to the errors that occur once the final check result has
+/// been published (i.e. syncing the failure comment), so that they are not
+/// reported as a failed check replacing the actual result.
+#[derive(Debug, Error)]
+#[error("error after publishing the check result")]
+struct CheckResultPublished;
+
+/// Marker attached to the errors that occur while the check is in progress,
+/// so that the in progress check result is updated when reporting them.
+#[derive(Debug, Error)]
+#[error("error while the check was in progress")]
+struct CheckInProgress {
+    check_run_id: Option<i64>,
+    config: Config,
+    ctx: Ctx,
+    head_sha: String,
+}
+
 /// Process check run event.
 async fn process_check_run_event(gh_client: DynGHClient, event: &CheckRunEvent) -> Result<()> {
     let started_at = Utc::now();
@@ -61,32 +262,272 @@ async fn process_check_run_event(gh_client: DynGHClient, event: &CheckRunEvent)
         return Ok(());
     }
 
-    // Override: create check run with success status
+    // Override: update the check run with success status
     if let Some(requested_action) = &event.requested_action
         && requested_action.identifier == OVERRIDE_ACTION_IDENTIFIER
     {
-        // Ignore override requests when the repository disabled this action
+        // Ignore override requests when the repository disabled this action.
+        // The configuration is read from the pull request's base branch when
+        // the repository requires it
         let config = gh_client
             .get_config(&ctx)
             .await
             .context("error getting repository configuration")?
             .unwrap_or_default();
-        if !config.override_action_is_allowed() {
+        let config = if config.base_branch_config_is_used() {
+            match check_run_base_sha(gh_client.clone(), &ctx, &event.check_run).await? {
+                Some(base_sha) => config_at_base(gh_client.clone(), &ctx, config, &base_sha)
+                    .await
+                    .context("error getting repository configuration")?,
+                None => config,
+            }
+        } else {
+            config
+        };
+        if !config.override_action_is_allowed() || config.override_justification_is_required() {
+            return Ok(());
+        }
+
+        // When the override action is restricted, make sure the user who
+        // requested it is allowed to use it. Otherwise, the failed check is
+        // updated to include a note explaining why it wasn't overridden (any
+        // note added by a previous request is replaced)
+        let login = &event.sender.login;
+        if !user_can_override(gh_client.clone(), &ctx, &config, login)
+            .await
+            .context("error checking if user can override")?
+        {
+            let (conclusion, failed_title) = failed_check_conclusion_and_title(&config);
+            let original_summary = event.check_run.output.summary.as_deref().unwrap_or_default();
+            let original_summary =
+                original_summary.split(OVERRIDE_NOT_ALLOWED_MARKER).next().unwrap_or_default().trim_end();
+            let summary = format!(
+                "{original_summary}\n\n{OVERRIDE_NOT_ALLOWED_MARKER}\n\n> [!WARNING]\n> @{login} requested to set the check result to passed, but they are not allowed to override the DCO check in this repository."
+            );
+            let check_run = CheckRun::new(NewCheckRunInput {
+                actions: vec![override_action()],
+                completed_at: Some(Utc::now()),
+                conclusion: Some(conclusion),
+                head_sha: event.check_run.head_sha.clone(),
+                name: CHECK_NAME.to_string(),
+                started_at,
+                status: CheckRunStatus::Completed,
+                summary,
+                title: event.check_run.output.title.clone().unwrap_or(failed_title.to_string()),
+            });
+            let check_run_id = Some(event.check_run.id);
+            publish_check_result(gh_client.clone(), &ctx, &config, &check_run, check_run_id, None).await?;
             return Ok(());
         }
 
         let check_run = CheckRun::new(NewCheckRunInput {
             actions: vec![],
-            completed_at: Utc::now(),
-            conclusion: CheckRunConclusion::Success,
+            completed_at: Some(Utc::now()),
+            conclusion: Some(CheckRunConclusion::Success),
             head_sha: event.check_run.head_sha.clone(),
             name: CHECK_NAME.to_string(),
             started_at,
             status: CheckRunStatus::Completed,
-            summary: OVERRIDE_ACTION_SUMMARY.to_string(),
-            title: OVERRIDE_ACTION_SUMMARY.to_string(),
+            summary: format!("{OVERRIDE_ACTION_SUMMARY} by @{login}."),
+            title: format!("{OVERRIDE_ACTION_SUMMARY} by @{login}"),
         });
-        gh_client.create_check_run(&ctx, &check_run).await.context("error creating check run")?;
+        let check_run_id = Some(event.check_run.id);
+        publish_check_result(gh_client.clone(), &ctx, &config, &check_run, check_run_id, None).await?;
+
+        // Clean up the failure comment and label in the pull requests
+        if config.failure_comment_is_enabled() || config.failure_label().is_some() {
+            let pull_requests = check_run_pull_requests(gh_client.clone(), &ctx, &event.check_run)
+                .await
+                .context(CheckResultPublished)?;
+            for pull_request_number in pull_requests {
+                sync_failure_comment(gh_client.clone(), &ctx, &config, pull_request_number, None)
+                    .await
+                    .context(CheckResultPublished)?;
+                sync_failure_label(gh_client.clone(), &ctx, &config, pull_request_number, None, false)
+                    .await
+                    .context(CheckResultPublished)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Process installation event.
+async fn process_installation_event(gh_client: DynGHClient, event: &InstallationEvent) -> Result<()> {
+    match event.action {
+        // Check the open pull requests in the repositories the application
+        // has been installed on, as they have no check run yet
+        InstallationEventAction::Created => {
+            let repositories = event.repositories.iter().flatten();
+            check_repositories_open_pull_requests(gh_client, &event.installation, repositories).await?;
+        }
+        // Purge the data cached for the account the application has been
+        // uninstalled from
+        InstallationEventAction::Deleted => {
+            gh_client.purge_account_cache(&event.installation.account.login).await;
+        }
+        InstallationEventAction::Other => {}
+    }
+
+    Ok(())
+}
+
+/// Process installation repositories event.
+async fn process_installation_repositories_event(
+    gh_client: DynGHClient,
+    event: &InstallationRepositoriesEvent,
+) -> Result<()> {
+    // Check if we are interested in the event action
+    if event.action != InstallationRepositoriesEventAction::Added {
+        return Ok(());
+    }
+
+    // Check the open pull requests in the repositories added to the
+    // installation, as they have no check run yet
+    check_repositories_open_pull_requests(gh_client, &event.installation, &event.repositories_added).await
+}
+
+/// Process issue comment event.
+async fn process_issue_comment_event(gh_client: DynGHClient, event: &IssueCommentEvent) -> Result<()> {
+    let started_at = Utc::now();
+    let ctx = event.ctx();
+
+    // Check if we are interested in the event action
+    if event.action != IssueCommentEventAction::Created {
+        return Ok(());
+    }
+
+    // Commands are only supported in pull requests comments
+    if event.issue.pull_request.is_none() {
+        return Ok(());
+    }
+    let Some(command) = Command::parse(&event.comment.body) else {
+        return Ok(());
+    };
+
+    // Limit how often some commands can be requested on each pull request
+    if command.is_rate_limited() && !try_register_command_run(&ctx, event.issue.number) {
+        return Ok(());
+    }
+
+    // Get repository configuration
+    let config = gh_client
+        .get_config(&ctx)
+        .await
+        .context("error getting repository configuration")?
+        .unwrap_or_default();
+
+    match command {
+        Command::Override { reason } => {
+            // Ignore override requests when no reason was provided
+            if reason.is_empty() {
+                return Ok(());
+            }
+
+            // Ignore override requests when the repository disabled overrides
+            // (reading the configuration from the pull request's base branch
+            // when the repository requires it)
+            let pull_request = gh_client
+                .get_pull_request(&ctx, event.issue.number)
+                .await
+                .context("error getting pull request")?;
+            let config = config_at_base(gh_client.clone(), &ctx, config, &pull_request.base.sha)
+                .await
+                .context("error getting repository configuration")?;
+            if !config.override_action_is_allowed() {
+                return Ok(());
+            }
+
+            // Make sure the commenter is allowed to override the check. Anyone
+            // can comment on a pull request, so when the override is not
+            // restricted in the config a minimum permission is required
+            let login = &event.sender.login;
+            let user_is_allowed = if config.override_action_is_restricted() {
+                user_can_override(gh_client.clone(), &ctx, &config, login)
+                    .await
+                    .context("error checking if user can override")?
+            } else {
+                gh_client
+                    .get_repository_permission(&ctx, login)
+                    .await
+                    .context("error getting repository permission")?
+                    >= OVERRIDE_COMMAND_MIN_PERMISSION
+            };
+            if !user_is_allowed {
+                return Ok(());
+            }
+
+            // Create check run with success status on the pull request head
+            let summary = format!(
+                "{OVERRIDE_ACTION_SUMMARY} by @{login} ([comment]({})).\n\n**Reason:** {reason}",
+                event.comment.html_url
+            );
+            let check_run = CheckRun::new(NewCheckRunInput {
+                actions: vec![],
+                completed_at: Some(Utc::now()),
+                conclusion: Some(CheckRunConclusion::Success),
+                head_sha: pull_request.head.sha,
+                name: CHECK_NAME.to_string(),
+                started_at,
+                status: CheckRunStatus::Completed,
+                summary,
+                title: format!("{OVERRIDE_ACTION_SUMMARY} by @{login}"),
+            });
+            publish_check_result(gh_client.clone(), &ctx, &config, &check_run, None, None).await?;
+            sync_failure_comment(gh_client.clone(), &ctx, &config, event.issue.number, None)
+                .await
+                .context(CheckResultPublished)?;
+            let labels = Some(pull_request.labels.as_slice());
+            sync_failure_label(gh_client, &ctx, &config, event.issue.number, labels, false)
+                .await
+                .context(CheckResultPublished)?;
+        }
+        Command::Explain => {
+            // Run DCO check on the pull request commits
+            let pull_request = gh_client
+                .get_pull_request(&ctx, event.issue.number)
+                .await
+                .context("error getting pull request")?;
+            let config = config_at_base(gh_client.clone(), &ctx, config, &pull_request.base.sha)
+                .await
+                .context("error getting repository configuration")?;
+            let commits = gh_client
+                .compare_commits(&ctx, &pull_request.base.sha, &pull_request.head.sha)
+                .await
+                .context("error getting pull request commits")?
+                .commits;
+            let org = event.organization.as_ref().map(|o| o.login.as_str());
+            let output = run_check(
+                gh_client.clone(),
+                &ctx,
+                config,
+                org,
+                commits,
+                pull_request.head.ref_.clone(),
+                Some(pull_request.squash_commit_message()),
+            )
+            .await?;
+
+            // Reply with the explanation of the check result
+            let explanation =
+                CheckExplanation::new(&output).render().context("error rendering explanation template")?;
+            gh_client
+                .create_issue_comment(&ctx, event.issue.number, &explanation)
+                .await
+                .context("error creating issue comment")?;
+        }
+        Command::Recheck => {
+            let pull_request = gh_client
+                .get_pull_request(&ctx, event.issue.number)
+                .await
+                .context("error getting pull request")?;
+            let config = config_at_base(gh_client.clone(), &ctx, config, &pull_request.base.sha)
+                .await
+                .context("error getting repository configuration")?;
+            let org = event.organization.as_ref().map(|o| o.login.as_str());
+            check_pull_request(gh_client, &ctx, config, org, &pull_request, None, started_at).await?;
+        }
     }
 
     Ok(())
@@ -97,24 +538,151 @@ async fn process_merge_group_event(gh_client: DynGHClient, event: &MergeGroupEve
     let started_at = Utc::now();
     let ctx = event.ctx();
 
-    // Create a check run with success status when checks are requested for a
-    // merge group. The DCO check must already have passed before the pull
-    // request was added to the merge queue, so there is no need to run it again
+    // Check if we are interested in the event action
     if event.action != MergeGroupEventAction::ChecksRequested {
         return Ok(());
     }
-    let check_run = CheckRun::new(NewCheckRunInput {
-        actions: vec![],
-        completed_at: Utc::now(),
-        conclusion: CheckRunConclusion::Success,
-        head_sha: event.merge_group.head_commit.id.clone(),
-        name: CHECK_NAME.to_string(),
-        started_at,
-        status: CheckRunStatus::Completed,
-        summary: MERGE_GROUP_CHECKS_REQUESTED_SUMMARY.to_string(),
-        title: MERGE_GROUP_CHECKS_REQUESTED_SUMMARY.to_string(),
-    });
-    gh_client.create_check_run(&ctx, &check_run).await.context("error creating check run")?;
+
+    // Get repository configuration. When it is not valid, a failed check
+    // run explaining what is wrong is created
+    let base_sha = &event.merge_group.base_sha;
+    let config = match get_config_for_base(gh_client.clone(), &ctx, base_sha).await {
+        Ok(config) => config,
+        Err(err) => {
+            if let Some(config_err) = err.downcast_ref::<ConfigError>() {
+                return publish_config_error(
+                    gh_client,
+                    &ctx,
+                    &event.merge_group.head_commit.id,
+                    config_err,
+                    err.downcast_ref::<ConfigSource>(),
+                    None,
+                    started_at,
+                )
+                .await;
+            }
+            return Err(err.context("error getting repository configuration"));
+        }
+    };
+
+    // Create a check run with success status when checks are requested for a
+    // merge group, unless the repository requires checking its commits. The
+    // DCO check must already have passed before the pull request was added to
+    // the merge queue, so there is no need to run it again by default
+    if !config.merge_group_check_is_enabled() {
+        let check_run = CheckRun::new(NewCheckRunInput {
+            actions: vec![],
+            completed_at: Some(Utc::now()),
+            conclusion: Some(CheckRunConclusion::Success),
+            head_sha: event.merge_group.head_commit.id.clone(),
+            name: CHECK_NAME.to_string(),
+            started_at,
+            status: CheckRunStatus::Completed,
+            summary: MERGE_GROUP_CHECKS_REQUESTED_SUMMARY.to_string(),
+            title: MERGE_GROUP_CHECKS_REQUESTED_SUMMARY.to_string(),
+        });
+        publish_check_result(gh_client.clone(), &ctx, &config, &check_run, None, None).await?;
+        return Ok(());
+    }
+
+    // Report the check as in progress while the commits are being checked
+    let head_sha = &event.merge_group.head_commit.id;
+    let check_run_id =
+        publish_check_in_progress(gh_client.clone(), &ctx, &config, head_sha, started_at).await?;
+
+    let in_progress = CheckInProgress {
+        check_run_id,
+        config: config.clone(),
+        ctx: ctx.clone(),
+        head_sha: head_sha.clone(),
+    };
+    let result: Result<()> = async {
+        // Get merge group commits
+        let commits = gh_client
+            .compare_commits(
+                &ctx,
+                &event.merge_group.base_sha,
+                &event.merge_group.head_commit.id,
+            )
+            .await
+            .context("error getting merge group commits")?
+            .commits;
+
+        // Run DCO check
+        let org = event.organization.as_ref().map(|o| o.login.as_str());
+        let output = run_check(gh_client.clone(), &ctx, config, org, commits, String::new(), None).await?;
+
+        // Find the pull requests that broke the merge group (if any)
+        let mut pull_requests = vec![];
+        for commit_output in output.commits.iter().filter(|c| !c.errors.is_empty()) {
+            let commit_pull_requests = gh_client
+                .list_commit_pull_requests(&ctx, &commit_output.commit.sha)
+                .await
+                .context("error listing commit pull requests")?;
+            for pull_request in commit_pull_requests {
+                if !pull_requests.contains(&pull_request.number) {
+                    pull_requests.push(pull_request.number);
+                }
+            }
+        }
+
+        // Create check run
+        let (conclusion, title) = if output.num_commits_with_errors == 0 {
+            (CheckRunConclusion::Success, CHECK_PASSED_TITLE)
+        } else {
+            failed_check_conclusion_and_title(&output.config)
+        };
+        let status_description = output.status_description();
+        let output = MergeGroupCheckOutput {
+            commits: output.commits,
+            config: output.config,
+            num_commits_with_errors: output.num_commits_with_errors,
+            pull_requests,
+        };
+        let check_run = CheckRun::new(NewCheckRunInput {
+            actions: vec![],
+            completed_at: Some(Utc::now()),
+            conclusion: Some(conclusion),
+            head_sha: event.merge_group.head_commit.id.clone(),
+            name: CHECK_NAME.to_string(),
+            started_at,
+            status: CheckRunStatus::Completed,
+            summary: output.render().context("error rendering output template")?,
+            title: title.to_string(),
+        });
+        publish_check_result(
+            gh_client.clone(),
+            &ctx,
+            &output.config,
+            &check_run,
+            check_run_id,
+            Some(status_description),
+        )
+        .await?;
+
+        Ok(())
+    }
+    .await;
+    result.map_err(|err| err.context(in_progress))
+}
+
+/// Process organization event.
+async fn process_organization_event(gh_client: DynGHClient, event: &OrganizationEvent) -> Result<()> {
+    // Check if we are interested in the event action
+    if !matches!(
+        event.action,
+        OrganizationEventAction::MemberAdded | OrganizationEventAction::MemberRemoved
+    ) {
+        return Ok(());
+    }
+
+    // Invalidate the cached membership, so that the change is taken into
+    // account the next time the check runs
+    if let Some(membership) = &event.membership {
+        gh_client
+            .invalidate_organization_member_cache(&event.organization.login, &membership.user.login)
+            .await;
+    }
 
     Ok(())
 }
@@ -126,72 +694,1127 @@ async fn process_pull_request_event(gh_client: DynGHClient, event: &PullRequestE
 
     // Check if we are interested in the event action
     if ![
+        PullRequestEventAction::Edited,
+        PullRequestEventAction::Labeled,
         PullRequestEventAction::Opened,
+        PullRequestEventAction::ReadyForReview,
         PullRequestEventAction::Synchronize,
+        PullRequestEventAction::Unlabeled,
     ]
     .contains(&event.action)
     {
         return Ok(());
     }
 
-    // Get pull request commits
-    let commits: Vec<Commit> = gh_client
-        .compare_commits(&ctx, &event.pull_request.base.sha, &event.pull_request.head.sha)
+    // Get repository configuration. When it is not valid, a failed check
+    // run explaining what is wrong is created
+    let base_sha = &event.pull_request.base.sha;
+    let config = match get_config_for_base(gh_client.clone(), &ctx, base_sha).await {
+        Ok(config) => config,
+        Err(err) => {
+            if let Some(config_err) = err.downcast_ref::<ConfigError>() {
+                let org = event.organization.as_ref().map(|o| o.login.as_str());
+                let config_preview =
+                    preview_pull_request_config(gh_client.clone(), &ctx, org, &event.pull_request).await?;
+                return publish_config_error(
+                    gh_client,
+                    &ctx,
+                    &event.pull_request.head.sha,
+                    config_err,
+                    err.downcast_ref::<ConfigSource>(),
+                    config_preview,
+                    started_at,
+                )
+                .await;
+            }
+            return Err(err.context("error getting repository configuration"));
+        }
+    };
+
+    // Edited events are only relevant when the pull request description is
+    // used to validate the commits (squash merge mode) and it has changed
+    if event.action == PullRequestEventAction::Edited
+        && (!config.squash_merge_is_enabled()
+            || !event.changes.as_ref().is_some_and(PullRequestEventChanges::description_changed))
+    {
+        return Ok(());
+    }
+
+    // Labeled and unlabeled events are only relevant when the label involved
+    // is the exemption label
+    if [PullRequestEventAction::Labeled, PullRequestEventAction::Unlabeled].contains(&event.action)
+        && (config.exemption_label().is_none()
+            || event.label.as_ref().map(|l| l.name.as_str()) != config.exemption_label())
+    {
+        return Ok(());
+    }
+
+    let org = event.organization.as_ref().map(|o| o.login.as_str());
+    let labeled_by = (event.action == PullRequestEventAction::Labeled).then_some(event.sender.login.as_str());
+    check_pull_request(
+        gh_client,
+        &ctx,
+        config,
+        org,
+        &event.pull_request,
+        labeled_by,
+        started_at,
+    )
+    .await
+}
+
+/// Process push event.
+async fn process_push_event(gh_client: DynGHClient, event: &PushEvent) -> Result<()> {
+    let ctx = event.ctx();
+    let org = event.organization.as_ref().map(|o| o.login.as_str());
+
+    // When the configuration file was modified, invalidate the cached one so
+    // that the new one is used the next time the check runs. The open pull
+    // requests are checked again if the change was in the default branch
+    let mut open_pull_requests_result = Ok(());
+    if event.touches_any(&CONFIG_FILE_PATHS) {
+        gh_client.invalidate_config_cache(&ctx.owner, &ctx.repo).await;
+        if event.is_to_default_branch() {
+            open_pull_requests_result = check_open_pull_requests(gh_client.clone(), &ctx, org).await;
+        }
+    }
+
+    // Check the commits pushed directly to the branch (when enabled). This is
+    // done even if checking the open pull requests failed
+    check_push(gh_client, &ctx, org, event).await.and(open_pull_requests_result)
+}
+
+/// Run the DCO check on the commits pushed directly to a branch and create a
+/// check run with the result on the pushed head commit, when the check is
+/// enabled for the branch in the configuration. Optionally, an issue listing
+/// the commits that did not pass the check is opened.
+async fn check_push(gh_client: DynGHClient, ctx: &Ctx, org: Option<&str>, event: &PushEvent) -> Result<()> {
+    let started_at = Utc::now();
+
+    // Only pushes that update an existing branch are checked (new branches
+    // do not have a previous commit to compare with)
+    let Some(branch) = event.branch() else {
+        return Ok(());
+    };
+    if event.created || event.deleted || event.commits.is_empty() {
+        return Ok(());
+    }
+
+    // Get repository configuration. Pushes to the default branch use the
+    // configuration as it was before the push, so that the push itself cannot
+    // disable the check. It only needs to be fetched at that commit when the
+    // push modified the configuration file, as otherwise it matches the
+    // current (cached) one. When it is not valid the push is not checked, as
+    // it is not possible to know if checking pushes is enabled (the error is
+    // reported in pull requests)
+    let config = if !event.is_to_default_branch() {
+        get_config_for_base(gh_client.clone(), ctx, &event.before).await
+    } else if event.touches_any(&CONFIG_FILE_PATHS) {
+        gh_client.get_config_at(ctx, &event.before).await.map(Option::unwrap_or_default)
+    } else {
+        gh_client.get_config(ctx).await.map(Option::unwrap_or_default)
+    };
+    let config = match config {
+        Ok(config) => config,
+        Err(err) => {
+            if err.downcast_ref::<ConfigError>().is_some() {
+                return Ok(());
+            }
+            return Err(err.context("error getting repository configuration"));
+        }
+    };
+    if !config.push_check_is_enabled(branch) {
+        return Ok(());
+    }
+
+    // Get pushed commits, skipping the ones that belong to a merged pull
+    // request (i.e. when the push is the result of merging it), as they were
+    // already checked in the pull request
+    let mut commits: Vec<Commit> = vec![];
+    for commit in gh_client
+        .compare_commits(ctx, &event.before, &event.after)
         .await
-        .context("error getting pull request commits")?;
+        .context("error getting pushed commits")?
+        .commits
+    {
+        let pull_requests = gh_client
+            .list_commit_pull_requests(ctx, &commit.sha)
+            .await
+            .context("error listing commit pull requests")?;
+        if !pull_requests.iter().any(|pull_request| pull_request.merged_at.is_some()) {
+            commits.push(commit);
+        }
+    }
+    if commits.is_empty() {
+        return Ok(());
+    }
 
-    // Get repository configuration
-    let config = gh_client
-        .get_config(&ctx)
+    // Report the check as in progress while the commits are being checked
+    let check_run_id =
+        publish_check_in_progress(gh_client.clone(), ctx, &config, &event.after, started_at).await?;
+
+    let in_progress = CheckInProgress {
+        check_run_id,
+        config: config.clone(),
+        ctx: ctx.clone(),
+        head_sha: event.after.clone(),
+    };
+    let result: Result<()> = async {
+        // Run DCO check
+        let output = run_check(
+            gh_client.clone(),
+            ctx,
+            config,
+            org,
+            commits,
+            branch.to_string(),
+            None,
+        )
+        .await?;
+
+        // Create check run
+        let (conclusion, title) = if output.num_commits_with_errors == 0 {
+            (CheckRunConclusion::Success, CHECK_PASSED_TITLE)
+        } else {
+            failed_check_conclusion_and_title(&output.config)
+        };
+        let status_description = output.status_description();
+        let output = PushCheckOutput {
+            branch: branch.to_string(),
+            commits: output.commits,
+            config: output.config,
+            num_commits_with_errors: output.num_commits_with_errors,
+        };
+        let check_run = CheckRun::new(NewCheckRunInput {
+            actions: vec![],
+            completed_at: Some(Utc::now()),
+            conclusion: Some(conclusion),
+            head_sha: event.after.clone(),
+            name: CHECK_NAME.to_string(),
+            started_at,
+            status: CheckRunStatus::Completed,
+            summary: output.render().context("error rendering output template")?,
+            title: title.to_string(),
+        });
+        publish_check_result(
+            gh_client.clone(),
+            ctx,
+            &output.config,
+            &check_run,
+            check_run_id,
+            Some(status_description),
+        )
+        .await?;
+
+        // Open an issue listing the commits that did not pass the check
+        if output.num_commits_with_errors > 0 && output.config.push_issue_is_enabled() {
+            let issue = PushIssue {
+                branch: output.branch,
+                commits: output.commits,
+                num_commits_with_errors: output.num_commits_with_errors,
+                pusher: event.sender.login.clone(),
+            };
+            let title = format!("{PUSH_ISSUE_TITLE_PREFIX} `{}`", issue.branch);
+            let body = issue.render().context("error rendering push issue template")?;
+            gh_client
+                .create_issue(ctx, &title, &body)
+                .await
+                .context("error creating push issue")
+                .context(CheckResultPublished)?;
+        }
+
+        Ok(())
+    }
+    .await;
+    result.map_err(|err| err.context(in_progress))
+}
+
+/// Run the DCO check on all the open pull requests in the repository, using
+/// the configuration that applies to each of them. Up to
+/// OPEN_PULL_REQUESTS_CHECK_CONCURRENCY pull requests are checked at the same
+/// time. Errors checking a pull request do not prevent the others from being
+/// checked; the first one found is returned once all of them are done.
+async fn check_open_pull_requests(gh_client: DynGHClient, ctx: &Ctx, org: Option<&str>) -> Result<()> {
+    let started_at = Utc::now();
+
+    let mut pull_requests =
+        gh_client.list_open_pull_requests(ctx).await.context("error listing open pull requests")?;
+    if pull_requests.len() > OPEN_PULL_REQUESTS_CHECK_LIMIT {
+        let skipped: Vec<String> = pull_requests
+            .split_off(OPEN_PULL_REQUESTS_CHECK_LIMIT)
+            .iter()
+            .map(|pull_request| format!("#{}", pull_request.number))
+            .collect();
+        warn!(
+            owner = ctx.owner,
+            repo = ctx.repo,
+            skipped = skipped.join(", "),
+            "open pull requests check limit reached, some pull requests were skipped"
+        );
+    }
+
+    let mut tasks = JoinSet::new();
+    let mut first_err = None;
+    for pull_request in pull_requests {
+        // Wait for a check to finish when the concurrency limit is reached
+        if tasks.len() >= OPEN_PULL_REQUESTS_CHECK_CONCURRENCY
+            && let Some(Err(err)) = tasks.join_next().await.map(flatten_task_result)
+        {
+            first_err.get_or_insert(err);
+        }
+
+        let gh_client = gh_client.clone();
+        let ctx = ctx.clone();
+        let org = org.map(ToString::to_string);
+        tasks.spawn(async move {
+            let config = match get_config_for_base(gh_client.clone(), &ctx, &pull_request.base.sha).await {
+                Ok(config) => config,
+                Err(err) => {
+                    if let Some(config_err) = err.downcast_ref::<ConfigError>() {
+                        let config_preview = preview_pull_request_config(
+                            gh_client.clone(),
+                            &ctx,
+                            org.as_deref(),
+                            &pull_request,
+                        )
+                        .await?;
+                        return publish_config_error(
+                            gh_client,
+                            &ctx,
+                            &pull_request.head.sha,
+                            config_err,
+                            err.downcast_ref::<ConfigSource>(),
+                            config_preview,
+                            started_at,
+                        )
+                        .await;
+                    }
+                    return Err(err.context("error getting repository configuration"));
+                }
+            };
+            let result = check_pull_request(
+                gh_client.clone(),
+                &ctx,
+                config,
+                org.as_deref(),
+                &pull_request,
+                None,
+                started_at,
+            )
+            .await
+            .with_context(|| format!("error checking pull request #{}", pull_request.number));
+
+            // Only one error is reported for all the pull requests checked,
+            // so the check runs left in progress are completed here. When
+            // that fails, the error is returned as is so that it can still be
+            // reported later
+            let Err(err) = result else {
+                return Ok(());
+            };
+            if let Some(in_progress) = err.downcast_ref::<CheckInProgress>()
+                && complete_check_in_progress(gh_client, in_progress, None, started_at).await.is_ok()
+            {
+                return Err(err.context(CheckResultPublished));
+            }
+            Err(err)
+        });
+    }
+    while let Some(task_result) = tasks.join_next().await {
+        if let Err(err) = flatten_task_result(task_result) {
+            first_err.get_or_insert(err);
+        }
+    }
+
+    match first_err {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Check the open pull requests in the installation repositories provided.
+/// An error in a repository does not prevent the remaining ones from being
+/// checked, and all the errors are reported together at the end.
+async fn check_repositories_open_pull_requests<'a>(
+    gh_client: DynGHClient,
+    installation: &InstallationDetails,
+    repositories: impl IntoIterator<Item = &'a InstallationRepository>,
+) -> Result<()> {
+    let org = installation.org();
+    let mut errors = vec![];
+    for repository in repositories {
+        let ctx = installation.ctx(repository);
+        if let Err(err) = check_open_pull_requests(gh_client.clone(), &ctx, org).await {
+            errors.push(err.context(format!(
+                "error checking open pull requests in {}/{}",
+                ctx.owner, ctx.repo
+            )));
+        }
+    }
+
+    // A single error is returned as is, so that it can still be reported
+    // using the markers attached to it
+    match errors.len() {
+        0 => Ok(()),
+        1 => Err(errors.remove(0)),
+        n => {
+            let errors: Vec<String> = errors.iter().map(|err| format!("{err:#}")).collect();
+            bail!(
+                "errors checking open pull requests in {n} repositories:\n{}",
+                errors.join("\n")
+            )
+        }
+    }
+}
+
+/// Flatten the result of a task spawned to check a pull request.
+fn flatten_task_result(task_result: Result<Result<()>, tokio::task::JoinError>) -> Result<()> {
+    task_result.context("error joining pull request check task")?
+}
+
+/// Run the DCO check on the pull request provided and create a check run with
+/// the result. When the exemption label has just been applied to the pull
+/// request, the user who applied it must be provided in `labeled_by`.
+async fn check_pull_request(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    config: Config,
+    org: Option<&str>,
+    pull_request: &PullRequest,
+    labeled_by: Option<&str>,
+    started_at: DateTime<Utc>,
+) -> Result<()> {
+    // Create a check run with success status when the check is not enforced
+    // on the pull request's base branch
+    let base_ref = &pull_request.base.ref_;
+    if !config.branch_is_enforced(base_ref) {
+        let summary = format!(
+            "The DCO check is not enforced on pull requests targeting the `{base_ref}` branch, so the check result was set to passed."
+        );
+        let check_run = CheckRun::new(NewCheckRunInput {
+            actions: vec![],
+            completed_at: Some(Utc::now()),
+            conclusion: Some(CheckRunConclusion::Success),
+            head_sha: pull_request.head.sha.clone(),
+            name: CHECK_NAME.to_string(),
+            started_at,
+            status: CheckRunStatus::Completed,
+            summary,
+            title: CHECK_SKIPPED_BRANCH_TITLE.to_string(),
+        });
+        publish_check_result(gh_client.clone(), ctx, &config, &check_run, None, None).await?;
+        sync_failure_comment(gh_client.clone(), ctx, &config, pull_request.number, None)
+            .await
+            .context(CheckResultPublished)?;
+        let labels = Some(pull_request.labels.as_slice());
+        sync_failure_label(gh_client, ctx, &config, pull_request.number, labels, false)
+            .await
+            .context(CheckResultPublished)?;
+        return Ok(());
+    }
+
+    // Create a check run with neutral status when the pull request is a draft
+    // and the check is deferred until it is ready for review
+    if pull_request.draft && config.drafts_are_deferred() {
+        let check_run = CheckRun::new(NewCheckRunInput {
+            actions: vec![],
+            completed_at: Some(Utc::now()),
+            conclusion: Some(CheckRunConclusion::Neutral),
+            head_sha: pull_request.head.sha.clone(),
+            name: CHECK_NAME.to_string(),
+            started_at,
+            status: CheckRunStatus::Completed,
+            summary: CHECK_DEFERRED_DRAFT_SUMMARY.to_string(),
+            title: CHECK_DEFERRED_DRAFT_TITLE.to_string(),
+        });
+        publish_check_result(gh_client.clone(), ctx, &config, &check_run, None, None).await?;
+        return Ok(());
+    }
+
+    // Create a check run with success status when the pull request has the
+    // exemption label and it was applied by a user allowed to do so
+    if let Some(label) = config.exemption_label()
+        && pull_request.has_label(label)
+        && let Some(applier) =
+            get_exemption_label_applier(gh_client.clone(), ctx, pull_request, label, labeled_by)
+                .await
+                .context("error getting exemption label applier")?
+    {
+        let summary = format!(
+            "This pull request was exempted from the DCO check by @{applier}, who applied the `{label}` label, so the check result was set to passed."
+        );
+        let check_run = CheckRun::new(NewCheckRunInput {
+            actions: vec![],
+            completed_at: Some(Utc::now()),
+            conclusion: Some(CheckRunConclusion::Success),
+            head_sha: pull_request.head.sha.clone(),
+            name: CHECK_NAME.to_string(),
+            started_at,
+            status: CheckRunStatus::Completed,
+            summary,
+            title: CHECK_EXEMPTED_LABEL_TITLE.to_string(),
+        });
+        publish_check_result(gh_client.clone(), ctx, &config, &check_run, None, None).await?;
+        sync_failure_comment(gh_client.clone(), ctx, &config, pull_request.number, None)
+            .await
+            .context(CheckResultPublished)?;
+        let labels = Some(pull_request.labels.as_slice());
+        sync_failure_label(gh_client, ctx, &config, pull_request.number, labels, false)
+            .await
+            .context(CheckResultPublished)?;
+        return Ok(());
+    }
+
+    // Report the check as in progress while the commits are being checked
+    let check_run_id = publish_check_in_progress(
+        gh_client.clone(),
+        ctx,
+        &config,
+        &pull_request.head.sha,
+        started_at,
+    )
+    .await?;
+
+    let in_progress = CheckInProgress {
+        check_run_id,
+        config: config.clone(),
+        ctx: ctx.clone(),
+        head_sha: pull_request.head.sha.clone(),
+    };
+    let result: Result<()> = async {
+        // Get pull request commits and run DCO check
+        let comparison = gh_client
+            .compare_commits(ctx, &pull_request.base.sha, &pull_request.head.sha)
+            .await
+            .context("error getting pull request commits")?;
+        let output = run_check(
+            gh_client.clone(),
+            ctx,
+            config,
+            org,
+            comparison.commits.clone(),
+            pull_request.head.ref_.clone(),
+            Some(pull_request.squash_commit_message()),
+        )
+        .await?;
+
+        // Preview the new configuration when the pull request modifies it
+        let config_preview = preview_config(gh_client.clone(), ctx, org, pull_request, comparison)
+            .await
+            .context("error previewing configuration")?;
+
+        // Create check run
+        let (conclusion, title, actions) = if output.num_commits_with_errors == 0 {
+            (CheckRunConclusion::Success, CHECK_PASSED_TITLE, vec![])
+        } else {
+            let actions = if output.config.override_action_is_allowed()
+                && !output.config.override_justification_is_required()
+            {
+                vec![override_action()]
+            } else {
+                vec![]
+            };
+
+            let (conclusion, title) = failed_check_conclusion_and_title(&output.config);
+            (conclusion, title, actions)
+        };
+        let mut summary = output.render().context("error rendering output template")?;
+        if let Some(config_preview) = config_preview {
+            summary.push_str("\n\n");
+            summary.push_str(&config_preview.render().context("error rendering config preview template")?);
+        }
+        let check_run = CheckRun::new(NewCheckRunInput {
+            actions,
+            completed_at: Some(Utc::now()),
+            conclusion: Some(conclusion),
+            head_sha: pull_request.head.sha.clone(),
+            name: CHECK_NAME.to_string(),
+            started_at,
+            status: CheckRunStatus::Completed,
+            summary,
+            title: title.to_string(),
+        });
+        publish_check_result(
+            gh_client.clone(),
+            ctx,
+            &output.config,
+            &check_run,
+            check_run_id,
+            Some(output.status_description()),
+        )
+        .await?;
+
+        // Keep the failure comment and label in sync with the check result
+        let failed = output.num_commits_with_errors > 0;
+        let failure_comment = failed.then(|| CheckFailureComment::new(&output));
+        sync_failure_comment(
+            gh_client.clone(),
+            ctx,
+            &output.config,
+            pull_request.number,
+            failure_comment,
+        )
         .await
-        .context("error getting repository configuration")?
-        .unwrap_or_default();
+        .context(CheckResultPublished)?;
+        let labels = Some(pull_request.labels.as_slice());
+        sync_failure_label(
+            gh_client,
+            ctx,
+            &output.config,
+            pull_request.number,
+            labels,
+            failed,
+        )
+        .await
+        .context(CheckResultPublished)?;
+
+        Ok(())
+    }
+    .await;
+    result.map_err(|err| err.context(in_progress))
+}
+
+/// Get the configuration to use to check the pull request (or merge group)
+/// targeting the base commit provided.
+async fn get_config_for_base(gh_client: DynGHClient, ctx: &Ctx, base_sha: &str) -> Result<Config> {
+    let config = gh_client.get_config(ctx).await?.unwrap_or_default();
+    config_at_base(gh_client, ctx, config, base_sha).await
+}
+
+/// Get the configuration at the base commit provided when the configuration
+/// from the default branch requires it. Otherwise, the configuration provided
+/// is returned as is.
+async fn config_at_base(gh_client: DynGHClient, ctx: &Ctx, config: Config, base_sha: &str) -> Result<Config> {
+    if !config.base_branch_config_is_used() {
+        return Ok(config);
+    }
+    Ok(gh_client.get_config_at(ctx, base_sha).await?.unwrap_or_default())
+}
+
+/// Publish a failed check result explaining that the repository
+/// configuration is not valid, so the check could not run. The file the
+/// error comes from is reported when it is known, as well as the preview of
+/// the configuration modified by the pull request (if any).
+async fn publish_config_error(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    head_sha: &str,
+    config_err: &ConfigError,
+    config_source: Option<&ConfigSource>,
+    config_preview: Option<ConfigPreview>,
+    started_at: DateTime<Utc>,
+) -> Result<()> {
+    let details = match config_err {
+        ConfigError::Validation(errors) => errors.join("\n"),
+        ConfigError::Parse { .. } => config_err.to_string(),
+    };
+    let file = config_source.map(|source| format!(" ({source})")).unwrap_or_default();
+    let mut summary = format!(
+        "The DCO check could not run because the configuration file{file} is not valid:\n\n```text\n{details}\n```\n\nPlease fix the configuration file. For more details about the supported options, please see the [reference configuration file](https://github.com/cncf/dco2/blob/main/docs/config/dco.yml)."
+    );
+    if let Some(config_preview) = config_preview {
+        summary.push_str("\n\n");
+        summary.push_str(&config_preview.render().context("error rendering config preview template")?);
+    }
+    let check_run = CheckRun::new(NewCheckRunInput {
+        actions: vec![],
+        completed_at: Some(Utc::now()),
+        conclusion: Some(CheckRunConclusion::Failure),
+        head_sha: head_sha.to_string(),
+        name: CHECK_NAME.to_string(),
+        started_at,
+        status: CheckRunStatus::Completed,
+        summary,
+        title: CHECK_INVALID_CONFIG_TITLE.to_string(),
+    });
+
+    // The configuration could not be loaded, so the default one is used to
+    // decide how the result is published
+    publish_check_result(gh_client, ctx, &Config::default(), &check_run, None, None).await
+}
+
+/// Publish that the check is in progress on the commit provided, creating an
+/// in progress check run and/or a pending commit status depending on the
+/// configuration. The id of the check run created (if any) is returned, so
+/// that it can be updated once the check completes.
+async fn publish_check_in_progress(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    config: &Config,
+    head_sha: &str,
+    started_at: DateTime<Utc>,
+) -> Result<Option<i64>> {
+    let check_run = CheckRun::new(NewCheckRunInput {
+        actions: vec![],
+        completed_at: None,
+        conclusion: None,
+        head_sha: head_sha.to_string(),
+        name: CHECK_NAME.to_string(),
+        started_at,
+        status: CheckRunStatus::InProgress,
+        summary: CHECK_IN_PROGRESS_SUMMARY.to_string(),
+        title: CHECK_IN_PROGRESS_TITLE.to_string(),
+    });
+    let mut check_run_id = None;
+    if config.check_run_is_reported() {
+        check_run_id =
+            Some(gh_client.create_check_run(ctx, &check_run).await.context("error creating check run")?);
+    }
+    publish_commit_status(gh_client, ctx, config, &check_run, None).await?;
+
+    Ok(check_run_id)
+}
+
+/// Publish the check result, creating a check run and/or a commit status
+/// depending on the configuration. When the id of an existing check run is
+/// provided (i.e. an in progress one), it is updated instead of creating a
+/// new one. When no commit status description is provided, the check run
+/// title is used.
+async fn publish_check_result(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    config: &Config,
+    check_run: &CheckRun,
+    check_run_id: Option<i64>,
+    status_description: Option<String>,
+) -> Result<()> {
+    if config.check_run_is_reported() {
+        if let Some(check_run_id) = check_run_id {
+            gh_client
+                .update_check_run(ctx, check_run_id, check_run)
+                .await
+                .context("error updating check run")?;
+        } else {
+            gh_client.create_check_run(ctx, check_run).await.context("error creating check run")?;
+        }
+    }
+    publish_commit_status(gh_client, ctx, config, check_run, status_description).await
+}
+
+/// Publish the check run provided as a commit status (when enabled in the
+/// configuration). Check runs not completed yet are reported as pending.
+async fn publish_commit_status(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    config: &Config,
+    check_run: &CheckRun,
+    status_description: Option<String>,
+) -> Result<()> {
+    if config.commit_status_is_reported() {
+        let commit_status = CommitStatus::new(NewCommitStatusInput {
+            context: check_run.name().to_string(),
+            description: status_description.unwrap_or_else(|| check_run.title().to_string()),
+            sha: check_run.head_sha().to_string(),
+            state: check_run.conclusion().map_or(CommitStatusState::Pending, Into::into),
+        });
+        gh_client
+            .create_commit_status(ctx, &commit_status)
+            .await
+            .context("error creating commit status")?;
+    }
+
+    Ok(())
+}
+
+/// Create or update the failure comment in the pull request when the check
+/// failed, or delete it when it passed (if enabled in the configuration).
+async fn sync_failure_comment(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    config: &Config,
+    pull_request_number: i64,
+    failure_comment: Option<CheckFailureComment>,
+) -> Result<()> {
+    if !config.failure_comment_is_enabled() {
+        return Ok(());
+    }
+
+    let comment_id = gh_client
+        .find_issue_comment(ctx, pull_request_number, FAILURE_COMMENT_MARKER)
+        .await
+        .context("error finding failure comment")?;
+    match (failure_comment, comment_id) {
+        (Some(failure_comment), comment_id) => {
+            let body = failure_comment.render().context("error rendering failure comment template")?;
+            if let Some(comment_id) = comment_id {
+                gh_client
+                    .update_issue_comment(ctx, comment_id, &body)
+                    .await
+                    .context("error updating failure comment")?;
+            } else {
+                gh_client
+                    .create_issue_comment(ctx, pull_request_number, &body)
+                    .await
+                    .context("error creating failure comment")?;
+            }
+        }
+        (None, Some(comment_id)) => {
+            gh_client
+                .delete_issue_comment(ctx, comment_id)
+                .await
+                .context("error deleting failure comment")?;
+        }
+        (None, None) => {}
+    }
+
+    Ok(())
+}
+
+/// Add the failure label to the pull request when the check failed, or remove
+/// it when it passed (if enabled in the configuration). When the pull request
+/// labels are not provided, the label is removed unconditionally.
+async fn sync_failure_label(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    config: &Config,
+    pull_request_number: i64,
+    labels: Option<&[Label]>,
+    failed: bool,
+) -> Result<()> {
+    let Some(label) = config.failure_label() else {
+        return Ok(());
+    };
+
+    let has_label = labels.map(|labels| labels.iter().any(|l| l.name == label));
+    if failed && has_label != Some(true) {
+        gh_client
+            .create_label_if_missing(ctx, label, FAILURE_LABEL_COLOR, FAILURE_LABEL_DESCRIPTION)
+            .await
+            .context("error creating failure label")?;
+        gh_client
+            .add_issue_label(ctx, pull_request_number, label)
+            .await
+            .context("error adding failure label")?;
+    } else if !failed && has_label != Some(false) {
+        gh_client
+            .remove_issue_label(ctx, pull_request_number, label)
+            .await
+            .context("error removing failure label")?;
+    }
+
+    Ok(())
+}
+
+/// Run the DCO check on the commits provided, collecting first the extra
+/// information the check may need depending on the configuration.
+async fn run_check(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    config: Config,
+    org: Option<&str>,
+    mut commits: Vec<Commit>,
+    head_ref: String,
+    pull_request_description: Option<String>,
+) -> Result<CheckOutput> {
+    collect_commits_files(gh_client.clone(), ctx, &config, &mut commits)
+        .await
+        .context("error collecting commits files")?;
+    let cherry_picked_commits = collect_cherry_picked_commits(gh_client.clone(), ctx, &config, &commits)
+        .await
+        .context("error collecting cherry-picked commits")?;
 
     // Create a list of members that are not required to sign-off commits
     let mut members = vec![];
     if !config.members_signoff_is_required() {
-        members = collect_members(gh_client.clone(), event, &commits)
+        members = collect_members(gh_client.clone(), ctx, org, &commits)
             .await
-            .context("error collecting members")?
-    };
+            .context("error collecting members")?;
+    }
 
     // Run DCO check
     let input = CheckInput {
+        cherry_picked_commits,
         commits,
         config,
-        head_ref: event.pull_request.head.ref_.clone(),
+        head_ref,
         members,
+        pull_request_description,
     };
-    let output = check(&input);
 
-    // Create check run
-    let (conclusion, title, actions) = if output.num_commits_with_errors == 0 {
-        (CheckRunConclusion::Success, CHECK_PASSED_TITLE, vec![])
-    } else {
-        let actions = if output.config.override_action_is_allowed() {
-            vec![CheckRunAction {
-                label: OVERRIDE_ACTION_LABEL.to_string(),
-                description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
-                identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
-            }]
-        } else {
-            vec![]
+    Ok(check(&input))
+}
+
+/// Preview the configuration modified by the pull request provided, if any,
+/// checking the pull request commits using the new configuration. The files
+/// modified are the ones in the comparison between the pull request base and
+/// head commits.
+async fn preview_config(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    org: Option<&str>,
+    pull_request: &PullRequest,
+    comparison: Comparison,
+) -> Result<Option<ConfigPreview>> {
+    // Check if any of the configuration files has been modified
+    let modified_files: Vec<&str> = CONFIG_FILE_PATHS
+        .into_iter()
+        .filter(|path| comparison.files.iter().any(|file| file == path))
+        .collect();
+    if modified_files.is_empty() {
+        return Ok(None);
+    }
+
+    // Only the configuration file with the highest precedence takes effect,
+    // so the modified files with a lower precedence than the one loaded are
+    // reported as ignored
+    let ignored_files = |source: Option<&ConfigSource>| -> Vec<String> {
+        let Some(source) = source.filter(|source| source.owner == ctx.owner && source.repo == ctx.repo)
+        else {
+            return vec![];
         };
+        let precedence = |path: &str| CONFIG_FILE_PATHS.iter().position(|p| *p == path);
+        modified_files
+            .iter()
+            .filter(|file| precedence(file) > precedence(&source.path))
+            .map(ToString::to_string)
+            .collect()
+    };
 
-        (CheckRunConclusion::ActionRequired, CHECK_FAILED_TITLE, actions)
+    // Get the new configuration (when no configuration file exists anymore,
+    // the default configuration will be used)
+    let config = match gh_client.get_config_at(ctx, &pull_request.head.sha).await {
+        Ok(config) => config.unwrap_or_default(),
+        Err(err) => {
+            if let Some(config_err) = err.downcast_ref::<ConfigError>() {
+                let source = err.downcast_ref::<ConfigSource>();
+                return Ok(Some(ConfigPreview::invalid(
+                    config_err,
+                    source.cloned(),
+                    ignored_files(source),
+                )));
+            }
+            return Err(err.context("error getting pull request configuration"));
+        }
     };
-    let check_run = CheckRun::new(NewCheckRunInput {
-        actions,
-        completed_at: Utc::now(),
-        conclusion,
-        head_sha: event.pull_request.head.sha.clone(),
-        name: CHECK_NAME.to_string(),
-        started_at,
-        status: CheckRunStatus::Completed,
-        summary: output.render().context("error rendering output template")?,
-        title: title.to_string(),
-    });
-    gh_client.create_check_run(&ctx, &check_run).await.context("error creating check run")?;
+    let source = config
+        .sources
+        .iter()
+        .find(|source| source.owner == ctx.owner && source.repo == ctx.repo)
+        .cloned();
+    let ignored_files = ignored_files(source.as_ref());
+
+    // Check the pull request commits using the new configuration
+    let output = run_check(
+        gh_client,
+        ctx,
+        config,
+        org,
+        comparison.commits,
+        pull_request.head.ref_.clone(),
+        Some(pull_request.squash_commit_message()),
+    )
+    .await?;
+
+    Ok(Some(ConfigPreview::new(&output, source, ignored_files)))
+}
+
+/// Preview the configuration modified by the pull request provided, if any,
+/// when the check cannot run because the current configuration is not valid.
+/// This allows verifying the pull requests that fix it before merging them.
+async fn preview_pull_request_config(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    org: Option<&str>,
+    pull_request: &PullRequest,
+) -> Result<Option<ConfigPreview>> {
+    let comparison = gh_client
+        .compare_commits(ctx, &pull_request.base.sha, &pull_request.head.sha)
+        .await
+        .context("error getting pull request commits")?;
+    preview_config(gh_client, ctx, org, pull_request, comparison)
+        .await
+        .context("error previewing configuration")
+}
+
+/// Get the numbers of the pull requests the check run provided belongs to.
+/// GitHub does not include the pull requests opened from forks in check run
+/// events, so when none are provided they are looked up by the head commit.
+async fn check_run_pull_requests(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    check_run: &CheckRunEventCheckRun,
+) -> Result<Vec<i64>> {
+    if !check_run.pull_requests.is_empty() {
+        return Ok(check_run.pull_requests.iter().map(|pull_request| pull_request.number).collect());
+    }
+    let pull_requests = gh_client
+        .list_commit_pull_requests(ctx, &check_run.head_sha)
+        .await
+        .context("error listing commit pull requests")?;
+    Ok(pull_requests.into_iter().map(|pull_request| pull_request.number).collect())
+}
+
+/// Get the base commit of the pull request the check run provided belongs
+/// to (if any). Check runs on pull requests from forks do not include them,
+/// so in that case the pull request is looked up by the head commit.
+async fn check_run_base_sha(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    check_run: &CheckRunEventCheckRun,
+) -> Result<Option<String>> {
+    if let Some(pull_request) = check_run.pull_requests.first() {
+        return Ok(Some(pull_request.base.sha.clone()));
+    }
+    let pull_requests = gh_client
+        .list_commit_pull_requests(ctx, &check_run.head_sha)
+        .await
+        .context("error listing commit pull requests")?;
+    let Some(pull_request) = pull_requests.first() else {
+        return Ok(None);
+    };
+    let pull_request = gh_client
+        .get_pull_request(ctx, pull_request.number)
+        .await
+        .context("error getting pull request")?;
+    Ok(Some(pull_request.base.sha))
+}
+
+/// Get the override action (set check result to passed).
+fn override_action() -> CheckRunAction {
+    CheckRunAction {
+        label: OVERRIDE_ACTION_LABEL.to_string(),
+        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+    }
+}
+
+/// Check if the user provided is allowed to override the check. When the
+/// override is not restricted in the configuration, any user can override it.
+async fn user_can_override(gh_client: DynGHClient, ctx: &Ctx, config: &Config, login: &str) -> Result<bool> {
+    if !config.override_action_is_restricted() {
+        return Ok(true);
+    }
+    let Some(allowed_by) = &config.override_allowed_by else {
+        return Ok(true);
+    };
+
+    // Users
+    if let Some(users) = &allowed_by.users
+        && users.iter().any(|user| user.eq_ignore_ascii_case(login))
+    {
+        return Ok(true);
+    }
+
+    // Teams (teams without organization belong to the repository owner)
+    for team in allowed_by.teams.iter().flatten() {
+        let (org, team_slug) = team.split_once('/').unwrap_or((&ctx.owner, team));
+        if gh_client
+            .is_team_member(ctx, org, team_slug, login)
+            .await
+            .context("error checking team membership")?
+        {
+            return Ok(true);
+        }
+    }
+
+    // Minimum permission
+    if let Some(minimum_permission) = allowed_by.minimum_permission {
+        let permission = gh_client
+            .get_repository_permission(ctx, login)
+            .await
+            .context("error getting repository permission")?;
+        if permission >= minimum_permission {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Get the conclusion and title of the check run when the check fails. In
+/// advisory mode, failed checks conclude neutral so that they don't block.
+fn failed_check_conclusion_and_title(config: &Config) -> (CheckRunConclusion, &'static str) {
+    if config.advisory_mode_is_enabled() {
+        (CheckRunConclusion::Neutral, CHECK_FAILED_ADVISORY_TITLE)
+    } else {
+        (CheckRunConclusion::ActionRequired, CHECK_FAILED_TITLE)
+    }
+}
+
+/// Get the login of the user who applied the exemption label to the pull
+/// request, provided they have enough permissions to exempt it.
+async fn get_exemption_label_applier(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    pull_request: &PullRequest,
+    label: &str,
+    labeled_by: Option<&str>,
+) -> Result<Option<String>> {
+    // When the label has just been applied, we already know who applied it.
+    // Otherwise we need to look it up in the pull request events
+    let applier = if let Some(labeled_by) = labeled_by {
+        Some(labeled_by.to_string())
+    } else {
+        gh_client
+            .get_label_applier(ctx, pull_request.number, label)
+            .await
+            .context("error getting label applier")?
+    };
+    let Some(applier) = applier else {
+        return Ok(None);
+    };
+
+    // Check the applier's permission on the repository
+    let permission = gh_client
+        .get_repository_permission(ctx, &applier)
+        .await
+        .context("error getting repository permission")?;
+    if permission < EXEMPTION_LABEL_MIN_PERMISSION {
+        return Ok(None);
+    }
+
+    Ok(Some(applier))
+}
+
+/// Collect the original commits referenced by the cherry-picked commits
+/// provided. They are only needed (and collected) when the configuration
+/// allows cherry-picked commits to rely on the original commit's sign-off.
+async fn collect_cherry_picked_commits(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    config: &Config,
+    commits: &[Commit],
+) -> Result<Vec<Commit>> {
+    let mut cherry_picked_commits: Vec<Commit> = vec![];
+    if !config.cherry_picks_are_allowed() {
+        return Ok(cherry_picked_commits);
+    }
+
+    let mut checked_shas = HashSet::new();
+    for commit in commits.iter().filter(|c| !c.is_merge) {
+        for sha in get_cherry_picked_shas(&commit.message) {
+            if !checked_shas.insert(sha.clone()) {
+                continue;
+            }
+            if let Some(original) =
+                gh_client.get_commit(ctx, &sha).await.context("error getting cherry-picked commit")?
+            {
+                cherry_picked_commits.push(original);
+            }
+        }
+    }
+
+    Ok(cherry_picked_commits)
+}
+
+/// Collect the files modified by each of the commits provided. Files are only
+/// needed (and collected) when the configuration contains exempt paths.
+async fn collect_commits_files(
+    gh_client: DynGHClient,
+    ctx: &Ctx,
+    config: &Config,
+    commits: &mut [Commit],
+) -> Result<()> {
+    if !config.exempt_paths_are_configured() {
+        return Ok(());
+    }
+
+    for commit in commits.iter_mut().filter(|c| !c.is_merge) {
+        let files = gh_client
+            .list_commit_files(ctx, &commit.sha)
+            .await
+            .context("error listing commit files")?;
+        commit.files = Some(files);
+    }
 
     Ok(())
 }
@@ -199,15 +1822,15 @@ async fn process_pull_request_event(gh_client: DynGHClient, event: &PullRequestE
 /// Create a list of members that are not required to sign-off commits.
 async fn collect_members(
     gh_client: DynGHClient,
-    event: &PullRequestEvent,
+    ctx: &Ctx,
+    org: Option<&str>,
     commits: &[Commit],
 ) -> Result<Vec<String>> {
     let mut checked_authors = HashSet::new();
     let mut members = vec![];
 
     // If the repository belongs to an organization, collect its members
-    let ctx = event.ctx();
-    if let Some(org) = event.organization.as_ref().map(|o| o.login.as_str()) {
+    if let Some(org) = org {
         for commit in commits {
             if !commit.verified.unwrap_or(false) {
                 continue;
@@ -218,7 +1841,7 @@ async fn collect_members(
                 && checked_authors.insert(author_username.to_string())
             {
                 let is_member = gh_client
-                    .is_organization_member(&ctx, org, author_username)
+                    .is_organization_member(ctx, org, author_username)
                     .await
                     .context("error checking organization membership")?;
                 if is_member {
@@ -228,8 +1851,64 @@ async fn collect_members(
         }
     } else {
         // Otherwise, the only member will be the repository owner
-        members.push(event.repository.owner.login.to_string());
+        members.push(ctx.owner.clone());
     }
 
     Ok(members)
 }
+
+/// Command requested in a pull request comment.
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    Explain,
+    Override { reason: String },
+    Recheck,
+}
+
+impl Command {
+    /// Parse the command at the beginning of the comment provided (if any).
+    fn parse(comment: &str) -> Option<Self> {
+        // The prefix must be followed by some whitespace and the command name
+        let rest = comment.trim_start().strip_prefix(COMMAND_PREFIX)?;
+        if !rest.starts_with(char::is_whitespace) {
+            return None;
+        }
+        let rest = rest.trim_start();
+        let (name, args) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+
+        match name {
+            "explain" => Some(Command::Explain),
+            "override" => Some(Command::Override {
+                reason: args.trim().to_string(),
+            }),
+            "recheck" => Some(Command::Recheck),
+            _ => None,
+        }
+    }
+
+    /// Check if the command is subject to rate limiting.
+    fn is_rate_limited(&self) -> bool {
+        matches!(self, Command::Explain | Command::Recheck)
+    }
+}
+
+/// Last time a rate limited command was run on each pull request.
+static COMMANDS_LAST_RUN: LazyLock<Mutex<HashMap<String, Instant>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Register a new run of a rate limited command on the pull request provided.
+/// Returns false when the rate limit was exceeded (the run is not registered).
+fn try_register_command_run(ctx: &Ctx, pull_request_number: i64) -> bool {
+    let mut last_run = COMMANDS_LAST_RUN.lock().expect("commands last run lock not to be poisoned");
+    let now = Instant::now();
+
+    // Remove expired entries, so that the map doesn't grow indefinitely
+    last_run.retain(|_, run_at| now.duration_since(*run_at) < COMMANDS_RATE_LIMIT_INTERVAL);
+
+    let key = format!("{}/{}#{}", ctx.owner, ctx.repo, pull_request_number);
+    if last_run.contains_key(&key) {
+        return false;
+    }
+    last_run.insert(key, now);
+    true
+}
@@ -1,24 +1,38 @@
 use std::{future, sync::Arc};
 
 use anyhow::{Ok, anyhow};
+use chrono::Utc;
 use indoc::indoc;
 use mockall::predicate::eq;
 
 use crate::{
     dco::{
+        check::FAILURE_COMMENT_MARKER,
         event::{
-            CHECK_FAILED_TITLE, CHECK_NAME, CHECK_PASSED_TITLE, MERGE_GROUP_CHECKS_REQUESTED_SUMMARY,
+            CHECK_DEFERRED_DRAFT_SUMMARY, CHECK_DEFERRED_DRAFT_TITLE, CHECK_EXEMPTED_LABEL_TITLE,
+            CHECK_FAILED_ADVISORY_TITLE, CHECK_FAILED_TITLE, CHECK_IN_PROGRESS_TITLE,
+            CHECK_INTERNAL_ERROR_TITLE, CHECK_INVALID_CONFIG_TITLE, CHECK_NAME, CHECK_PASSED_TITLE,
+            CHECK_SKIPPED_BRANCH_TITLE, CheckInProgress, CheckResultPublished, Command, FAILURE_LABEL_COLOR,
+            FAILURE_LABEL_DESCRIPTION, MERGE_GROUP_CHECKS_REQUESTED_SUMMARY, OPEN_PULL_REQUESTS_CHECK_LIMIT,
             OVERRIDE_ACTION_DESCRIPTION, OVERRIDE_ACTION_IDENTIFIER, OVERRIDE_ACTION_LABEL,
-            OVERRIDE_ACTION_SUMMARY,
+            OVERRIDE_ACTION_SUMMARY, OVERRIDE_NOT_ALLOWED_MARKER,
         },
-        process_event,
+        process_event, report_error,
     },
     github::{
         CheckRunAction, CheckRunConclusion, CheckRunEvent, CheckRunEventAction, CheckRunEventCheckRun,
-        CheckRunStatus, Commit, Config, ConfigRequire, Event, Installation, MergeGroupEvent,
-        MergeGroupEventAction, MergeGroupEventMergeGroup, MergeGroupHeadCommit, MockGHClient, Organization,
-        PullRequest, PullRequestBase, PullRequestEvent, PullRequestEventAction, PullRequestHead, Repository,
-        RepositoryOwner, RequestedAction, User,
+        CheckRunEventCheckRunOutput, CheckRunEventPullRequest, CheckRunStatus, Commit, CommitPullRequest,
+        CommitStatusState, Comparison, Config, ConfigBranches, ConfigCheckPushes, ConfigError, ConfigMode,
+        ConfigOverrideAllowedBy, ConfigPolicy, ConfigReportAs, ConfigRequire, ConfigSource, Ctx, DynGHClient,
+        Event, Installation, InstallationAccount, InstallationAccountType, InstallationDetails,
+        InstallationEvent, InstallationEventAction, InstallationRepositoriesEvent,
+        InstallationRepositoriesEventAction, InstallationRepository, Issue, IssueComment, IssueCommentEvent,
+        IssueCommentEventAction, IssuePullRequest, Label, MergeGroupEvent, MergeGroupEventAction,
+        MergeGroupEventMergeGroup, MergeGroupHeadCommit, MockGHClient, Organization, OrganizationEvent,
+        OrganizationEventAction, OrganizationMember, OrganizationMembership, PullRequest, PullRequestBase,
+        PullRequestEvent, PullRequestEventAction, PullRequestEventChange, PullRequestEventChanges,
+        PullRequestHead, PushEvent, PushEventCommit, PushEventRepository, Repository, RepositoryOwner,
+        RepositoryPermission, RequestedAction, Sender, User,
     },
 };
 
@@ -28,6 +42,12 @@ async fn check_run_event_other_action() {
         action: CheckRunEventAction::Other,
         check_run: CheckRunEventCheckRun {
             head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
+            },
+            pull_requests: vec![],
         },
         installation: Installation { id: 1 },
         repository: Repository {
@@ -37,11 +57,14 @@ async fn check_run_event_other_action() {
             },
         },
         requested_action: None,
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let gh_client = MockGHClient::new();
 
-    process_event(Arc::new(gh_client), &Event::CheckRun(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
@@ -50,6 +73,12 @@ async fn check_run_event_requested_action_unknown_identifier() {
         action: CheckRunEventAction::RequestedAction,
         check_run: CheckRunEventCheckRun {
             head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
+            },
+            pull_requests: vec![],
         },
         installation: Installation { id: 1 },
         repository: Repository {
@@ -61,20 +90,29 @@ async fn check_run_event_requested_action_unknown_identifier() {
         requested_action: Some(RequestedAction {
             identifier: "unknown".to_string(),
         }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let gh_client = MockGHClient::new();
 
-    process_event(Arc::new(gh_client), &Event::CheckRun(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-#[should_panic(expected = "error creating check run")]
-async fn check_run_event_requested_action_override_error_creating_check_run() {
+#[should_panic(expected = "error updating check run")]
+async fn check_run_event_requested_action_override_error_updating_check_run() {
     let event = CheckRunEvent {
         action: CheckRunEventAction::RequestedAction,
         check_run: CheckRunEventCheckRun {
             head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
+            },
+            pull_requests: vec![],
         },
         installation: Installation { id: 1 },
         repository: Repository {
@@ -86,6 +124,9 @@ async fn check_run_event_requested_action_override_error_creating_check_run() {
         requested_action: Some(RequestedAction {
             identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
         }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
@@ -96,22 +137,25 @@ async fn check_run_event_requested_action_override_error_creating_check_run() {
         .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
     let expected_ctx = event.ctx();
     gh_client
-        .expect_create_check_run()
-        .withf(move |ctx, check_run| {
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
             *ctx == expected_ctx
+                && *check_run_id == 1
                 && check_run.actions().is_empty()
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::Success
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
                 && check_run.head_sha() == "head_sha"
                 && check_run.name() == CHECK_NAME
                 && check_run.status() == &CheckRunStatus::Completed
-                && check_run.summary() == OVERRIDE_ACTION_SUMMARY
-                && check_run.title() == OVERRIDE_ACTION_SUMMARY
+                && check_run.summary() == format!("{OVERRIDE_ACTION_SUMMARY} by @user.")
+                && check_run.title() == format!("{OVERRIDE_ACTION_SUMMARY} by @user")
         })
         .times(1)
-        .returning(|_, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+        .returning(|_, _, _| Box::pin(future::ready(Err(anyhow!("test error")))));
 
-    process_event(Arc::new(gh_client), &Event::CheckRun(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
@@ -120,6 +164,12 @@ async fn check_run_event_requested_action_override_ignored_when_disabled() {
         action: CheckRunEventAction::RequestedAction,
         check_run: CheckRunEventCheckRun {
             head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
+            },
+            pull_requests: vec![],
         },
         installation: Installation { id: 1 },
         repository: Repository {
@@ -131,6 +181,9 @@ async fn check_run_event_requested_action_override_ignored_when_disabled() {
         requested_action: Some(RequestedAction {
             identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
         }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
@@ -141,15 +194,27 @@ async fn check_run_event_requested_action_override_ignored_when_disabled() {
         }))))
     });
 
-    process_event(Arc::new(gh_client), &Event::CheckRun(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-async fn check_run_event_requested_action_override_success() {
+async fn check_run_event_requested_action_override_ignored_when_disabled_in_base_branch() {
     let event = CheckRunEvent {
         action: CheckRunEventAction::RequestedAction,
         check_run: CheckRunEventCheckRun {
             head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
+            },
+            pull_requests: vec![CheckRunEventPullRequest {
+                base: PullRequestBase {
+                    ref_: "main".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                number: 1,
+            }],
         },
         installation: Installation { id: 1 },
         repository: Repository {
@@ -161,42 +226,44 @@ async fn check_run_event_requested_action_override_success() {
         requested_action: Some(RequestedAction {
             identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
         }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            use_base_branch_config: Some(true),
+            ..Default::default()
+        }))))
+    });
     gh_client
-        .expect_get_config()
-        .with(eq(event.ctx()))
-        .times(1)
-        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
-    let expected_ctx = event.ctx();
-    gh_client
-        .expect_create_check_run()
-        .withf(move |ctx, check_run| {
-            *ctx == expected_ctx
-                && check_run.actions().is_empty()
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::Success
-                && check_run.head_sha() == "head_sha"
-                && check_run.name() == CHECK_NAME
-                && check_run.status() == &CheckRunStatus::Completed
-                && check_run.summary() == OVERRIDE_ACTION_SUMMARY
-                && check_run.title() == OVERRIDE_ACTION_SUMMARY
-        })
+        .expect_get_config_at()
+        .with(eq(event.ctx()), eq("base_sha"))
         .times(1)
-        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(Some(Config {
+                allow_override_action: Some(false),
+                ..Default::default()
+            }))))
+        });
 
-    process_event(Arc::new(gh_client), &Event::CheckRun(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-async fn merge_group_other_action() {
-    let event = MergeGroupEvent {
-        action: MergeGroupEventAction::Other,
-        merge_group: MergeGroupEventMergeGroup {
-            head_commit: MergeGroupHeadCommit {
-                id: "head_sha".to_string(),
+async fn check_run_event_requested_action_override_ignored_when_disabled_in_base_branch_fork_pull_request() {
+    let event = CheckRunEvent {
+        action: CheckRunEventAction::RequestedAction,
+        check_run: CheckRunEventCheckRun {
+            head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
             },
+            pull_requests: vec![],
         },
         installation: Installation { id: 1 },
         repository: Repository {
@@ -205,22 +272,79 @@ async fn merge_group_other_action() {
                 login: "owner".to_string(),
             },
         },
+        requested_action: Some(RequestedAction {
+            identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+        }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
-    let gh_client = MockGHClient::new();
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            use_base_branch_config: Some(true),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_list_commit_pull_requests()
+        .with(eq(event.ctx()), eq("head_sha"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(vec![CommitPullRequest {
+                number: 1,
+                ..Default::default()
+            }])))
+        });
+    gh_client
+        .expect_get_pull_request()
+        .with(eq(event.ctx()), eq(1))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(PullRequest {
+                base: PullRequestBase {
+                    ref_: "main".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                body: None,
+                draft: false,
+                head: PullRequestHead {
+                    ref_: "head_ref".to_string(),
+                    sha: "head_sha".to_string(),
+                },
+                html_url: "url".to_string(),
+                labels: vec![],
+                number: 1,
+                title: "Test pull request".to_string(),
+            })))
+        });
+    gh_client
+        .expect_get_config_at()
+        .with(eq(event.ctx()), eq("base_sha"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(Some(Config {
+                allow_override_action: Some(false),
+                ..Default::default()
+            }))))
+        });
 
-    process_event(Arc::new(gh_client), &Event::MergeGroup(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-#[should_panic(expected = "error creating check run")]
-async fn merge_group_checks_requested_error_creating_check_run() {
-    let event = MergeGroupEvent {
-        action: MergeGroupEventAction::ChecksRequested,
-        merge_group: MergeGroupEventMergeGroup {
-            head_commit: MergeGroupHeadCommit {
-                id: "head_sha".to_string(),
+async fn check_run_event_requested_action_override_ignored_when_justification_required() {
+    let event = CheckRunEvent {
+        action: CheckRunEventAction::RequestedAction,
+        check_run: CheckRunEventCheckRun {
+            head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
             },
+            pull_requests: vec![],
         },
         installation: Installation { id: 1 },
         repository: Repository {
@@ -229,37 +353,37 @@ async fn merge_group_checks_requested_error_creating_check_run() {
                 login: "owner".to_string(),
             },
         },
+        requested_action: Some(RequestedAction {
+            identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+        }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
-    let expected_ctx = event.ctx();
-    gh_client
-        .expect_create_check_run()
-        .withf(move |ctx, check_run| {
-            *ctx == expected_ctx
-                && check_run.actions().is_empty()
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::Success
-                && check_run.head_sha() == "head_sha"
-                && check_run.name() == CHECK_NAME
-                && check_run.status() == &CheckRunStatus::Completed
-                && check_run.summary() == MERGE_GROUP_CHECKS_REQUESTED_SUMMARY
-                && check_run.title() == MERGE_GROUP_CHECKS_REQUESTED_SUMMARY
-        })
-        .times(1)
-        .returning(|_, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            require_override_justification: Some(true),
+            ..Default::default()
+        }))))
+    });
 
-    process_event(Arc::new(gh_client), &Event::MergeGroup(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-async fn merge_group_checks_requested_success() {
-    let event = MergeGroupEvent {
-        action: MergeGroupEventAction::ChecksRequested,
-        merge_group: MergeGroupEventMergeGroup {
-            head_commit: MergeGroupHeadCommit {
-                id: "head_sha".to_string(),
+async fn check_run_event_requested_action_override_success() {
+    let event = CheckRunEvent {
+        action: CheckRunEventAction::RequestedAction,
+        check_run: CheckRunEventCheckRun {
+            head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
             },
+            pull_requests: vec![],
         },
         installation: Installation { id: 1 },
         repository: Repository {
@@ -268,559 +392,6893 @@ async fn merge_group_checks_requested_success() {
                 login: "owner".to_string(),
             },
         },
+        requested_action: Some(RequestedAction {
+            identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+        }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
     let expected_ctx = event.ctx();
     gh_client
-        .expect_create_check_run()
-        .withf(move |ctx, check_run| {
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
             *ctx == expected_ctx
+                && *check_run_id == 1
                 && check_run.actions().is_empty()
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::Success
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
                 && check_run.head_sha() == "head_sha"
                 && check_run.name() == CHECK_NAME
                 && check_run.status() == &CheckRunStatus::Completed
-                && check_run.summary() == MERGE_GROUP_CHECKS_REQUESTED_SUMMARY
-                && check_run.title() == MERGE_GROUP_CHECKS_REQUESTED_SUMMARY
+                && check_run.summary() == format!("{OVERRIDE_ACTION_SUMMARY} by @user.")
+                && check_run.title() == format!("{OVERRIDE_ACTION_SUMMARY} by @user")
         })
         .times(1)
-        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
 
-    process_event(Arc::new(gh_client), &Event::MergeGroup(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-async fn pull_request_event_other_action() {
-    let event = PullRequestEvent {
-        action: PullRequestEventAction::Other,
-        installation: Installation { id: 1 },
-        organization: None,
-        pull_request: PullRequest {
-            base: PullRequestBase {
-                ref_: "base_ref".to_string(),
-                sha: "base_sha".to_string(),
-            },
-            head: PullRequestHead {
-                ref_: "head_ref".to_string(),
-                sha: "head_sha".to_string(),
+async fn check_run_event_requested_action_override_success_failure_label_removed() {
+    let event = CheckRunEvent {
+        action: CheckRunEventAction::RequestedAction,
+        check_run: CheckRunEventCheckRun {
+            head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
             },
-            html_url: "url".to_string(),
+            pull_requests: vec![CheckRunEventPullRequest {
+                base: PullRequestBase {
+                    ref_: "main".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                number: 1,
+            }],
         },
+        installation: Installation { id: 1 },
         repository: Repository {
             name: "repo".to_string(),
             owner: RepositoryOwner {
                 login: "owner".to_string(),
             },
         },
+        requested_action: Some(RequestedAction {
+            identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+        }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
-    let gh_client = MockGHClient::new();
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            failure_label: Some("dco: missing".to_string()),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary() == format!("{OVERRIDE_ACTION_SUMMARY} by @user.")
+                && check_run.title() == format!("{OVERRIDE_ACTION_SUMMARY} by @user")
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
 
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+    gh_client
+        .expect_remove_issue_label()
+        .with(eq(event.ctx()), eq(1), eq("dco: missing"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-#[should_panic(expected = "error getting pull request commits")]
-async fn pull_request_event_opened_action_error_getting_pr_commits() {
-    let event = PullRequestEvent {
-        action: PullRequestEventAction::Opened,
-        installation: Installation { id: 1 },
-        organization: None,
-        pull_request: PullRequest {
-            base: PullRequestBase {
-                ref_: "base_ref".to_string(),
-                sha: "base_sha".to_string(),
-            },
-            head: PullRequestHead {
-                ref_: "head_ref".to_string(),
-                sha: "head_sha".to_string(),
+async fn check_run_event_requested_action_override_success_failure_label_removed_fork_pull_request() {
+    let event = CheckRunEvent {
+        action: CheckRunEventAction::RequestedAction,
+        check_run: CheckRunEventCheckRun {
+            head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
             },
-            html_url: "url".to_string(),
+            pull_requests: vec![],
         },
+        installation: Installation { id: 1 },
         repository: Repository {
             name: "repo".to_string(),
             owner: RepositoryOwner {
                 login: "owner".to_string(),
             },
         },
+        requested_action: Some(RequestedAction {
+            identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+        }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            failure_label: Some("dco: missing".to_string()),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
     gh_client
-        .expect_compare_commits()
-        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary() == format!("{OVERRIDE_ACTION_SUMMARY} by @user.")
+                && check_run.title() == format!("{OVERRIDE_ACTION_SUMMARY} by @user")
+        })
         .times(1)
-        .returning(|_, _, _| Box::pin(future::ready(Err(anyhow!("test error")))));
-
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+    gh_client
+        .expect_list_commit_pull_requests()
+        .with(eq(event.ctx()), eq("head_sha"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(vec![CommitPullRequest {
+                number: 1,
+                ..Default::default()
+            }])))
+        });
+    gh_client
+        .expect_remove_issue_label()
+        .with(eq(event.ctx()), eq(1), eq("dco: missing"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-#[should_panic(expected = "error getting repository configuration")]
-async fn pull_request_event_opened_action_error_getting_repository_configuration() {
-    let event = PullRequestEvent {
-        action: PullRequestEventAction::Opened,
-        installation: Installation { id: 1 },
-        organization: None,
-        pull_request: PullRequest {
-            base: PullRequestBase {
-                ref_: "base_ref".to_string(),
-                sha: "base_sha".to_string(),
-            },
-            head: PullRequestHead {
-                ref_: "head_ref".to_string(),
-                sha: "head_sha".to_string(),
+async fn check_run_event_requested_action_override_success_user_allowed() {
+    let event = CheckRunEvent {
+        action: CheckRunEventAction::RequestedAction,
+        check_run: CheckRunEventCheckRun {
+            head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
             },
-            html_url: "url".to_string(),
+            pull_requests: vec![],
         },
+        installation: Installation { id: 1 },
         repository: Repository {
             name: "repo".to_string(),
             owner: RepositoryOwner {
                 login: "owner".to_string(),
             },
         },
+        requested_action: Some(RequestedAction {
+            identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+        }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            override_allowed_by: Some(ConfigOverrideAllowedBy {
+                users: Some(vec!["User".to_string()]),
+                teams: None,
+                minimum_permission: None,
+            }),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
     gh_client
-        .expect_compare_commits()
-        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
-        .times(1)
-        .returning(|_, _, _| {
-            Box::pin(future::ready(Ok(vec![Commit {
-                author: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    ..Default::default()
-                }),
-                committer: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    ..Default::default()
-                }),
-                message: indoc! {r"
-                    Test commit message
-
-                    Signed-off-by: user1 <user1@email.test>
-                "}
-                .to_string(),
-                ..Default::default()
-            }])))
-        });
-    gh_client
-        .expect_get_config()
-        .with(eq(event.ctx()))
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary() == format!("{OVERRIDE_ACTION_SUMMARY} by @user.")
+                && check_run.title() == format!("{OVERRIDE_ACTION_SUMMARY} by @user")
+        })
         .times(1)
-        .returning(|_| Box::pin(future::ready(Err(anyhow!("test error")))));
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
 
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-#[should_panic(expected = "error checking organization membership")]
-async fn pull_request_event_opened_action_error_checking_user_organization_membership() {
-    let event = PullRequestEvent {
-        action: PullRequestEventAction::Opened,
-        installation: Installation { id: 1 },
-        organization: Some(Organization {
-            login: "org".to_string(),
-        }),
-        pull_request: PullRequest {
-            base: PullRequestBase {
-                ref_: "base_ref".to_string(),
-                sha: "base_sha".to_string(),
-            },
-            head: PullRequestHead {
-                ref_: "head_ref".to_string(),
-                sha: "head_sha".to_string(),
+async fn check_run_event_requested_action_override_success_user_is_maintainer() {
+    let event = CheckRunEvent {
+        action: CheckRunEventAction::RequestedAction,
+        check_run: CheckRunEventCheckRun {
+            head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
             },
-            html_url: "url".to_string(),
+            pull_requests: vec![],
         },
+        installation: Installation { id: 1 },
         repository: Repository {
             name: "repo".to_string(),
             owner: RepositoryOwner {
                 login: "owner".to_string(),
             },
         },
+        requested_action: Some(RequestedAction {
+            identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+        }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
-    gh_client
-        .expect_compare_commits()
-        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
-        .times(1)
-        .returning(|_, _, _| {
-            Box::pin(future::ready(Ok(vec![Commit {
-                author: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    login: Some("user1".to_string()),
-                    ..Default::default()
-                }),
-                committer: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    login: Some("user1".to_string()),
-                    ..Default::default()
-                }),
-                message: "Test commit message".to_string(),
-                verified: Some(true),
-                ..Default::default()
-            }])))
-        });
     gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
         Box::pin(future::ready(Ok(Some(Config {
-            require: Some(ConfigRequire { members: Some(false) }),
+            override_allowed_by: Some(ConfigOverrideAllowedBy {
+                users: None,
+                teams: None,
+                minimum_permission: Some(RepositoryPermission::Maintain),
+            }),
             ..Default::default()
         }))))
     });
     gh_client
-        .expect_is_organization_member()
-        .with(eq(event.ctx()), eq("org"), eq("user1"))
+        .expect_get_repository_permission()
+        .with(eq(event.ctx()), eq("user"))
         .times(1)
-        .returning(|_, _, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+        .returning(|_, _| Box::pin(future::ready(Ok(RepositoryPermission::Maintain))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary() == format!("{OVERRIDE_ACTION_SUMMARY} by @user.")
+                && check_run.title() == format!("{OVERRIDE_ACTION_SUMMARY} by @user")
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
 
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-#[should_panic(expected = "error creating check run")]
-async fn pull_request_event_opened_action_error_creating_check_run() {
-    let event = PullRequestEvent {
-        action: PullRequestEventAction::Opened,
-        installation: Installation { id: 1 },
-        organization: None,
-        pull_request: PullRequest {
-            base: PullRequestBase {
-                ref_: "base_ref".to_string(),
-                sha: "base_sha".to_string(),
-            },
-            head: PullRequestHead {
-                ref_: "head_ref".to_string(),
-                sha: "head_sha".to_string(),
+async fn check_run_event_requested_action_override_success_user_is_team_member() {
+    let event = CheckRunEvent {
+        action: CheckRunEventAction::RequestedAction,
+        check_run: CheckRunEventCheckRun {
+            head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
             },
-            html_url: "url".to_string(),
+            pull_requests: vec![],
         },
+        installation: Installation { id: 1 },
         repository: Repository {
             name: "repo".to_string(),
             owner: RepositoryOwner {
                 login: "owner".to_string(),
             },
         },
+        requested_action: Some(RequestedAction {
+            identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+        }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            override_allowed_by: Some(ConfigOverrideAllowedBy {
+                users: Some(vec!["user2".to_string()]),
+                teams: Some(vec!["maintainers".to_string(), "org/legal".to_string()]),
+                minimum_permission: None,
+            }),
+            ..Default::default()
+        }))))
+    });
     gh_client
-        .expect_compare_commits()
-        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .expect_is_team_member()
+        .with(eq(event.ctx()), eq("owner"), eq("maintainers"), eq("user"))
         .times(1)
-        .returning(|_, _, _| {
-            Box::pin(future::ready(Ok(vec![Commit {
-                author: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    ..Default::default()
-                }),
-                committer: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    ..Default::default()
-                }),
-                message: indoc! {r"
-                    Test commit message
-
-                    Signed-off-by: user1 <user1@email.test>
-                "}
-                .to_string(),
-                ..Default::default()
-            }])))
-        });
+        .returning(|_, _, _, _| Box::pin(future::ready(Ok(false))));
     gh_client
-        .expect_get_config()
-        .with(eq(event.ctx()))
+        .expect_is_team_member()
+        .with(eq(event.ctx()), eq("org"), eq("legal"), eq("user"))
         .times(1)
-        .returning(|_| Box::pin(future::ready(Ok(None))));
+        .returning(|_, _, _, _| Box::pin(future::ready(Ok(true))));
     let expected_ctx = event.ctx();
     gh_client
-        .expect_create_check_run()
-        .withf(move |ctx, check_run| {
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
             *ctx == expected_ctx
+                && *check_run_id == 1
                 && check_run.actions().is_empty()
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::Success
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
                 && check_run.head_sha() == "head_sha"
                 && check_run.name() == CHECK_NAME
                 && check_run.status() == &CheckRunStatus::Completed
-                && check_run.title() == CHECK_PASSED_TITLE
+                && check_run.summary() == format!("{OVERRIDE_ACTION_SUMMARY} by @user.")
+                && check_run.title() == format!("{OVERRIDE_ACTION_SUMMARY} by @user")
         })
         .times(1)
-        .returning(|_, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
 
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-async fn pull_request_event_opened_action_success_check_passed() {
-    let event = PullRequestEvent {
-        action: PullRequestEventAction::Opened,
-        installation: Installation { id: 1 },
-        organization: None,
-        pull_request: PullRequest {
-            base: PullRequestBase {
-                ref_: "base_ref".to_string(),
-                sha: "base_sha".to_string(),
-            },
-            head: PullRequestHead {
-                ref_: "head_ref".to_string(),
-                sha: "head_sha".to_string(),
+async fn check_run_event_requested_action_override_user_not_allowed() {
+    let event = CheckRunEvent {
+        action: CheckRunEventAction::RequestedAction,
+        check_run: CheckRunEventCheckRun {
+            head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some("summary".to_string()),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
             },
-            html_url: "url".to_string(),
+            pull_requests: vec![],
         },
+        installation: Installation { id: 1 },
         repository: Repository {
             name: "repo".to_string(),
             owner: RepositoryOwner {
                 login: "owner".to_string(),
             },
         },
+        requested_action: Some(RequestedAction {
+            identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+        }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            override_allowed_by: Some(ConfigOverrideAllowedBy {
+                users: None,
+                teams: None,
+                minimum_permission: Some(RepositoryPermission::Admin),
+            }),
+            ..Default::default()
+        }))))
+    });
     gh_client
-        .expect_compare_commits()
-        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
-        .times(1)
-        .returning(|_, _, _| {
-            Box::pin(future::ready(Ok(vec![Commit {
-                author: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    ..Default::default()
-                }),
-                committer: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    ..Default::default()
-                }),
-                message: indoc! {r"
-                    Test commit message
-
-                    Signed-off-by: user1 <user1@email.test>
-                "}
-                .to_string(),
-                ..Default::default()
-            }])))
-        });
-    gh_client
-        .expect_get_config()
-        .with(eq(event.ctx()))
+        .expect_get_repository_permission()
+        .with(eq(event.ctx()), eq("user"))
         .times(1)
-        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+        .returning(|_, _| Box::pin(future::ready(Ok(RepositoryPermission::Write))));
     let expected_ctx = event.ctx();
     gh_client
-        .expect_create_check_run()
-        .withf(move |ctx, check_run| {
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
             *ctx == expected_ctx
-                && check_run.actions().is_empty()
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::Success
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
                 && check_run.head_sha() == "head_sha"
                 && check_run.name() == CHECK_NAME
                 && check_run.status() == &CheckRunStatus::Completed
-                && check_run.title() == CHECK_PASSED_TITLE
+                && check_run.summary().starts_with("summary")
+                && check_run.summary().contains("@user requested to set the check result to passed")
+                && check_run.title() == CHECK_FAILED_TITLE
         })
         .times(1)
-        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
 
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-async fn pull_request_event_opened_action_success_check_passed_author_is_member() {
-    let event = PullRequestEvent {
-        action: PullRequestEventAction::Opened,
-        installation: Installation { id: 1 },
-        organization: Some(Organization {
-            login: "org".to_string(),
-        }),
-        pull_request: PullRequest {
-            base: PullRequestBase {
-                ref_: "base_ref".to_string(),
-                sha: "base_sha".to_string(),
-            },
-            head: PullRequestHead {
-                ref_: "head_ref".to_string(),
-                sha: "head_sha".to_string(),
+async fn check_run_event_requested_action_override_user_not_allowed_previous_warning_replaced() {
+    let event = CheckRunEvent {
+        action: CheckRunEventAction::RequestedAction,
+        check_run: CheckRunEventCheckRun {
+            head_sha: "head_sha".to_string(),
+            id: 1,
+            output: CheckRunEventCheckRunOutput {
+                summary: Some(format!(
+                    "summary\n\n{OVERRIDE_NOT_ALLOWED_MARKER}\n\n> [!WARNING]\n> @user2 requested to set the check result to passed, but they are not allowed to override the DCO check in this repository."
+                )),
+                title: Some(CHECK_FAILED_TITLE.to_string()),
             },
-            html_url: "url".to_string(),
+            pull_requests: vec![],
         },
+        installation: Installation { id: 1 },
         repository: Repository {
             name: "repo".to_string(),
             owner: RepositoryOwner {
                 login: "owner".to_string(),
             },
         },
+        requested_action: Some(RequestedAction {
+            identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+        }),
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
-    gh_client
-        .expect_compare_commits()
-        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
-        .times(1)
-        .returning(|_, _, _| {
-            Box::pin(future::ready(Ok(vec![Commit {
-                author: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    login: Some("user1".to_string()),
-                    ..Default::default()
-                }),
-                committer: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    login: Some("user1".to_string()),
-                    ..Default::default()
-                }),
-                message: "Test commit message".to_string(),
-                verified: Some(true),
-                ..Default::default()
-            }])))
-        });
     gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
         Box::pin(future::ready(Ok(Some(Config {
-            require: Some(ConfigRequire { members: Some(false) }),
+            override_allowed_by: Some(ConfigOverrideAllowedBy {
+                users: None,
+                teams: None,
+                minimum_permission: Some(RepositoryPermission::Admin),
+            }),
             ..Default::default()
         }))))
     });
     gh_client
-        .expect_is_organization_member()
-        .with(eq(event.ctx()), eq("org"), eq("user1"))
+        .expect_get_repository_permission()
+        .with(eq(event.ctx()), eq("user"))
         .times(1)
-        .returning(|_, _, _| Box::pin(future::ready(Ok(true))));
+        .returning(|_, _| Box::pin(future::ready(Ok(RepositoryPermission::Write))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().starts_with("summary")
+                && check_run.summary().matches("[!WARNING]").count() == 1
+                && check_run.summary().contains("@user requested to set the check result to passed")
+                && !check_run.summary().contains("@user2")
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::CheckRun(Box::new(event))).await.unwrap();
+}
+
+#[test]
+fn command_parse() {
+    assert_eq!(
+        Command::parse("/dco override Third-party code imported with legal approval"),
+        Some(Command::Override {
+            reason: "Third-party code imported with legal approval".to_string()
+        })
+    );
+    assert_eq!(
+        Command::parse("  /dco   override\n\nReason\n"),
+        Some(Command::Override {
+            reason: "Reason".to_string()
+        })
+    );
+    assert_eq!(
+        Command::parse("/dco override"),
+        Some(Command::Override {
+            reason: String::new()
+        })
+    );
+    assert_eq!(Command::parse("/dco explain"), Some(Command::Explain));
+    assert_eq!(Command::parse("/dco recheck\n"), Some(Command::Recheck));
+    assert_eq!(Command::parse("/dcooverride reason"), None);
+    assert_eq!(Command::parse("/dco unknown"), None);
+    assert_eq!(Command::parse("Please run /dco override reason"), None);
+}
+
+#[tokio::test]
+async fn installation_event_other_action() {
+    let event = InstallationEvent {
+        action: InstallationEventAction::Other,
+        installation: InstallationDetails {
+            account: InstallationAccount {
+                login: "org".to_string(),
+                type_: InstallationAccountType::Organization,
+            },
+            id: 1,
+        },
+        repositories: None,
+    };
+
+    let gh_client = MockGHClient::new();
+
+    process_event(Arc::new(gh_client), &Event::Installation(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn installation_event_created_action_open_pull_requests_checked() {
+    let event = InstallationEvent {
+        action: InstallationEventAction::Created,
+        installation: InstallationDetails {
+            account: InstallationAccount {
+                login: "org".to_string(),
+                type_: InstallationAccountType::Organization,
+            },
+            id: 1,
+        },
+        repositories: Some(vec![InstallationRepository {
+            name: "repo".to_string(),
+        }]),
+    };
+    let expected_ctx = Ctx {
+        inst_id: 1,
+        owner: "org".to_string(),
+        repo: "repo".to_string(),
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_list_open_pull_requests()
+        .with(eq(expected_ctx.clone()))
+        .times(1)
+        .returning(|_| {
+            Box::pin(future::ready(Ok(vec![PullRequest {
+                base: PullRequestBase {
+                    ref_: "main".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                body: None,
+                draft: true,
+                head: PullRequestHead {
+                    ref_: "head_ref".to_string(),
+                    sha: "head_sha".to_string(),
+                },
+                html_url: "url".to_string(),
+                labels: vec![],
+                number: 1,
+                title: "Test pull request".to_string(),
+            }])))
+        });
+    gh_client.expect_get_config().with(eq(expected_ctx.clone())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            defer_drafts: Some(true),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.conclusion() == Some(&CheckRunConclusion::Neutral)
+                && check_run.head_sha() == "head_sha"
+                && check_run.title() == CHECK_DEFERRED_DRAFT_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+
+    process_event(Arc::new(gh_client), &Event::Installation(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn installation_event_created_action_error_listing_open_pull_requests() {
+    let event = InstallationEvent {
+        action: InstallationEventAction::Created,
+        installation: InstallationDetails {
+            account: InstallationAccount {
+                login: "user".to_string(),
+                type_: InstallationAccountType::User,
+            },
+            id: 1,
+        },
+        repositories: Some(vec![InstallationRepository {
+            name: "repo".to_string(),
+        }]),
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_list_open_pull_requests()
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Err(anyhow!("test error")))));
+
+    let result = process_event(Arc::new(gh_client), &Event::Installation(event)).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn installation_event_created_action_error_checking_pull_request_check_completed() {
+    let event = InstallationEvent {
+        action: InstallationEventAction::Created,
+        installation: InstallationDetails {
+            account: InstallationAccount {
+                login: "org".to_string(),
+                type_: InstallationAccountType::Organization,
+            },
+            id: 1,
+        },
+        repositories: Some(vec![InstallationRepository {
+            name: "repo".to_string(),
+        }]),
+    };
+    let expected_ctx = Ctx {
+        inst_id: 1,
+        owner: "org".to_string(),
+        repo: "repo".to_string(),
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_list_open_pull_requests()
+        .with(eq(expected_ctx.clone()))
+        .times(1)
+        .returning(|_| {
+            Box::pin(future::ready(Ok(vec![PullRequest {
+                base: PullRequestBase {
+                    ref_: "main".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                body: None,
+                draft: false,
+                head: PullRequestHead {
+                    ref_: "head_ref".to_string(),
+                    sha: "head_sha".to_string(),
+                },
+                html_url: "url".to_string(),
+                labels: vec![],
+                number: 1,
+                title: "Test pull request".to_string(),
+            }])))
+        });
+    gh_client
+        .expect_get_config()
+        .with(eq(expected_ctx.clone()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    gh_client
+        .expect_create_check_run()
+        .withf(|_, check_run| check_run.status() == &CheckRunStatus::InProgress)
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.conclusion() == Some(&CheckRunConclusion::Failure)
+                && check_run.head_sha() == "head_sha"
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_INTERNAL_ERROR_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    let err = process_event(Arc::new(gh_client), &Event::Installation(event)).await.unwrap_err();
+    assert!(err.downcast_ref::<CheckResultPublished>().is_some());
+}
+
+#[tokio::test]
+async fn installation_event_deleted_action_account_cache_purged() {
+    let event = InstallationEvent {
+        action: InstallationEventAction::Deleted,
+        installation: InstallationDetails {
+            account: InstallationAccount {
+                login: "org".to_string(),
+                type_: InstallationAccountType::Organization,
+            },
+            id: 1,
+        },
+        repositories: Some(vec![InstallationRepository {
+            name: "repo".to_string(),
+        }]),
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_purge_account_cache()
+        .with(eq("org"))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(())));
+
+    process_event(Arc::new(gh_client), &Event::Installation(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn installation_repositories_event_other_action() {
+    let event = InstallationRepositoriesEvent {
+        action: InstallationRepositoriesEventAction::Other,
+        installation: InstallationDetails {
+            account: InstallationAccount {
+                login: "org".to_string(),
+                type_: InstallationAccountType::Organization,
+            },
+            id: 1,
+        },
+        repositories_added: vec![],
+    };
+
+    let gh_client = MockGHClient::new();
+
+    process_event(Arc::new(gh_client), &Event::InstallationRepositories(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn installation_repositories_event_added_action_open_pull_requests_listed() {
+    let event = InstallationRepositoriesEvent {
+        action: InstallationRepositoriesEventAction::Added,
+        installation: InstallationDetails {
+            account: InstallationAccount {
+                login: "org".to_string(),
+                type_: InstallationAccountType::Organization,
+            },
+            id: 1,
+        },
+        repositories_added: vec![
+            InstallationRepository {
+                name: "repo1".to_string(),
+            },
+            InstallationRepository {
+                name: "repo2".to_string(),
+            },
+        ],
+    };
+
+    let mut gh_client = MockGHClient::new();
+    for repo in ["repo1", "repo2"] {
+        gh_client
+            .expect_list_open_pull_requests()
+            .with(eq(Ctx {
+                inst_id: 1,
+                owner: "org".to_string(),
+                repo: repo.to_string(),
+            }))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(vec![]))));
+    }
+
+    process_event(Arc::new(gh_client), &Event::InstallationRepositories(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn installation_repositories_event_added_action_errors_collected_per_repository() {
+    let event = InstallationRepositoriesEvent {
+        action: InstallationRepositoriesEventAction::Added,
+        installation: InstallationDetails {
+            account: InstallationAccount {
+                login: "org".to_string(),
+                type_: InstallationAccountType::Organization,
+            },
+            id: 1,
+        },
+        repositories_added: vec![
+            InstallationRepository {
+                name: "repo1".to_string(),
+            },
+            InstallationRepository {
+                name: "repo2".to_string(),
+            },
+            InstallationRepository {
+                name: "repo3".to_string(),
+            },
+        ],
+    };
+
+    let mut gh_client = MockGHClient::new();
+    for repo in ["repo1", "repo3"] {
+        gh_client
+            .expect_list_open_pull_requests()
+            .with(eq(Ctx {
+                inst_id: 1,
+                owner: "org".to_string(),
+                repo: repo.to_string(),
+            }))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Err(anyhow!("test error")))));
+    }
+    gh_client
+        .expect_list_open_pull_requests()
+        .with(eq(Ctx {
+            inst_id: 1,
+            owner: "org".to_string(),
+            repo: "repo2".to_string(),
+        }))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(vec![]))));
+
+    let err = process_event(Arc::new(gh_client), &Event::InstallationRepositories(event))
+        .await
+        .unwrap_err();
+    let err = format!("{err:#}");
+    assert!(err.contains("errors checking open pull requests in 2 repositories"));
+    assert!(err.contains("org/repo1"));
+    assert!(err.contains("org/repo3"));
+}
+
+#[tokio::test]
+async fn issue_comment_event_other_action() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Other,
+        comment: IssueComment {
+            body: "/dco override reason".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 1,
+            pull_request: Some(IssuePullRequest {
+                html_url: "pr_url".to_string(),
+            }),
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let gh_client = MockGHClient::new();
+
+    process_event(Arc::new(gh_client), &Event::IssueComment(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn issue_comment_event_created_action_not_a_pull_request() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Created,
+        comment: IssueComment {
+            body: "/dco override reason".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 1,
+            pull_request: None,
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let gh_client = MockGHClient::new();
+
+    process_event(Arc::new(gh_client), &Event::IssueComment(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn issue_comment_event_created_action_not_a_command() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Created,
+        comment: IssueComment {
+            body: "LGTM".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 1,
+            pull_request: Some(IssuePullRequest {
+                html_url: "pr_url".to_string(),
+            }),
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let gh_client = MockGHClient::new();
+
+    process_event(Arc::new(gh_client), &Event::IssueComment(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn issue_comment_event_created_action_override_without_reason() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Created,
+        comment: IssueComment {
+            body: "/dco override".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 1,
+            pull_request: Some(IssuePullRequest {
+                html_url: "pr_url".to_string(),
+            }),
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+
+    process_event(Arc::new(gh_client), &Event::IssueComment(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn issue_comment_event_created_action_override_user_not_allowed() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Created,
+        comment: IssueComment {
+            body: "/dco override reason".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 1,
+            pull_request: Some(IssuePullRequest {
+                html_url: "pr_url".to_string(),
+            }),
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    gh_client
+        .expect_get_repository_permission()
+        .with(eq(event.ctx()), eq("user"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(RepositoryPermission::Read))));
+    gh_client
+        .expect_get_pull_request()
+        .with(eq(event.ctx()), eq(1))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(PullRequest {
+                base: PullRequestBase {
+                    ref_: "base_ref".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                body: None,
+                draft: false,
+                head: PullRequestHead {
+                    ref_: "head_ref".to_string(),
+                    sha: "head_sha".to_string(),
+                },
+                html_url: "pr_url".to_string(),
+                labels: vec![],
+                number: 1,
+                title: "Test pull request".to_string(),
+            })))
+        });
+
+    process_event(Arc::new(gh_client), &Event::IssueComment(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn issue_comment_event_created_action_override_ignored_when_disabled_in_base_branch() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Created,
+        comment: IssueComment {
+            body: "/dco override Third-party code imported with legal approval".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 1,
+            pull_request: Some(IssuePullRequest {
+                html_url: "pr_url".to_string(),
+            }),
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            use_base_branch_config: Some(true),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_get_config_at()
+        .with(eq(event.ctx()), eq("base_sha"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(Some(Config {
+                allow_override_action: Some(false),
+                ..Default::default()
+            }))))
+        });
+    gh_client
+        .expect_get_pull_request()
+        .with(eq(event.ctx()), eq(1))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(PullRequest {
+                base: PullRequestBase {
+                    ref_: "base_ref".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                body: None,
+                draft: false,
+                head: PullRequestHead {
+                    ref_: "head_ref".to_string(),
+                    sha: "head_sha".to_string(),
+                },
+                html_url: "pr_url".to_string(),
+                labels: vec![],
+                number: 1,
+                title: "Test pull request".to_string(),
+            })))
+        });
+
+    process_event(Arc::new(gh_client), &Event::IssueComment(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn issue_comment_event_created_action_override_success() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Created,
+        comment: IssueComment {
+            body: "/dco override Third-party code imported with legal approval".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 1,
+            pull_request: Some(IssuePullRequest {
+                html_url: "pr_url".to_string(),
+            }),
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            require_override_justification: Some(true),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_get_repository_permission()
+        .with(eq(event.ctx()), eq("user"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(RepositoryPermission::Write))));
+    gh_client
+        .expect_get_pull_request()
+        .with(eq(event.ctx()), eq(1))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(PullRequest {
+                base: PullRequestBase {
+                    ref_: "base_ref".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                body: None,
+                draft: false,
+                head: PullRequestHead {
+                    ref_: "head_ref".to_string(),
+                    sha: "head_sha".to_string(),
+                },
+                html_url: "pr_url".to_string(),
+                labels: vec![],
+                number: 1,
+                title: "Test pull request".to_string(),
+            })))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains("@user ([comment](comment_url))")
+                && check_run.summary().contains("**Reason:** Third-party code imported with legal approval")
+                && check_run.title() == format!("{OVERRIDE_ACTION_SUMMARY} by @user")
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+
+    process_event(Arc::new(gh_client), &Event::IssueComment(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn issue_comment_event_created_action_recheck_success_check_failed() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Created,
+        comment: IssueComment {
+            body: "/dco recheck".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 101,
+            pull_request: Some(IssuePullRequest {
+                html_url: "pr_url".to_string(),
+            }),
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    gh_client
+        .expect_get_pull_request()
+        .with(eq(event.ctx()), eq(101))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(PullRequest {
+                base: PullRequestBase {
+                    ref_: "base_ref".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                body: None,
+                draft: false,
+                head: PullRequestHead {
+                    ref_: "head_ref".to_string(),
+                    sha: "head_sha".to_string(),
+                },
+                html_url: "pr_url".to_string(),
+                labels: vec![],
+                number: 101,
+                title: "Test pull request".to_string(),
+            })))
+        });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    sha: "sha1".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && !check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::IssueComment(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn issue_comment_event_created_action_recheck_rate_limited() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Created,
+        comment: IssueComment {
+            body: "/dco recheck".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 102,
+            pull_request: Some(IssuePullRequest {
+                html_url: "pr_url".to_string(),
+            }),
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    gh_client
+        .expect_get_pull_request()
+        .with(eq(event.ctx()), eq(102))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(PullRequest {
+                base: PullRequestBase {
+                    ref_: "base_ref".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                body: None,
+                draft: false,
+                head: PullRequestHead {
+                    ref_: "head_ref".to_string(),
+                    sha: "head_sha".to_string(),
+                },
+                html_url: "pr_url".to_string(),
+                labels: vec![],
+                number: 102,
+                title: "Test pull request".to_string(),
+            })))
+        });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    sha: "sha1".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && !check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::IssueComment(event.clone())).await.unwrap();
+
+    // The second recheck requested on the same pull request is ignored
+    let gh_client = MockGHClient::new();
+
+    process_event(Arc::new(gh_client), &Event::IssueComment(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn issue_comment_event_created_action_explain_success() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Created,
+        comment: IssueComment {
+            body: "/dco explain".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 103,
+            pull_request: Some(IssuePullRequest {
+                html_url: "pr_url".to_string(),
+            }),
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    gh_client
+        .expect_get_pull_request()
+        .with(eq(event.ctx()), eq(103))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(PullRequest {
+                base: PullRequestBase {
+                    ref_: "base_ref".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                body: None,
+                draft: false,
+                head: PullRequestHead {
+                    ref_: "head_ref".to_string(),
+                    sha: "head_sha".to_string(),
+                },
+                html_url: "pr_url".to_string(),
+                labels: vec![],
+                number: 103,
+                title: "Test pull request".to_string(),
+            })))
+        });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    sha: "sha1".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client
+        .expect_create_issue_comment()
+        .withf(|ctx, issue_number, body| {
+            ctx.owner == "owner"
+                && *issue_number == 103
+                && body.contains("There is **one commit** incorrectly signed off")
+                && body.contains("* **Sign-offs found**: none")
+                && body.contains("* **Errors**: sign-off not found")
+                && body.contains("Signed-off-by: user1 <user1@email.test>")
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::IssueComment(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn merge_group_other_action() {
+    let event = MergeGroupEvent {
+        action: MergeGroupEventAction::Other,
+        merge_group: MergeGroupEventMergeGroup {
+            base_sha: "base_sha".to_string(),
+            head_commit: MergeGroupHeadCommit {
+                id: "head_sha".to_string(),
+            },
+        },
+        installation: Installation { id: 1 },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+    };
+
+    let gh_client = MockGHClient::new();
+
+    process_event(Arc::new(gh_client), &Event::MergeGroup(event)).await.unwrap();
+}
+
+#[tokio::test]
+#[should_panic(expected = "error creating check run")]
+async fn merge_group_checks_requested_error_creating_check_run() {
+    let event = MergeGroupEvent {
+        action: MergeGroupEventAction::ChecksRequested,
+        merge_group: MergeGroupEventMergeGroup {
+            base_sha: "base_sha".to_string(),
+            head_commit: MergeGroupHeadCommit {
+                id: "head_sha".to_string(),
+            },
+        },
+        installation: Installation { id: 1 },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary() == MERGE_GROUP_CHECKS_REQUESTED_SUMMARY
+                && check_run.title() == MERGE_GROUP_CHECKS_REQUESTED_SUMMARY
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+
+    process_event(Arc::new(gh_client), &Event::MergeGroup(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn merge_group_checks_requested_success() {
+    let event = MergeGroupEvent {
+        action: MergeGroupEventAction::ChecksRequested,
+        merge_group: MergeGroupEventMergeGroup {
+            base_sha: "base_sha".to_string(),
+            head_commit: MergeGroupHeadCommit {
+                id: "head_sha".to_string(),
+            },
+        },
+        installation: Installation { id: 1 },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary() == MERGE_GROUP_CHECKS_REQUESTED_SUMMARY
+                && check_run.title() == MERGE_GROUP_CHECKS_REQUESTED_SUMMARY
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+
+    process_event(Arc::new(gh_client), &Event::MergeGroup(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn merge_group_checks_requested_check_enabled_error_getting_commits() {
+    let event = MergeGroupEvent {
+        action: MergeGroupEventAction::ChecksRequested,
+        merge_group: MergeGroupEventMergeGroup {
+            base_sha: "base_sha".to_string(),
+            head_commit: MergeGroupHeadCommit {
+                id: "head_sha".to_string(),
+            },
+        },
+        installation: Installation { id: 1 },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            check_merge_group: Some(true),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+
+    let err = process_event(Arc::new(gh_client), &Event::MergeGroup(event)).await.unwrap_err();
+    assert!(format!("{err:?}").contains("error getting merge group commits"));
+    let in_progress = err.downcast_ref::<CheckInProgress>().unwrap();
+    assert_eq!(in_progress.check_run_id, Some(1));
+}
+
+#[tokio::test]
+async fn merge_group_checks_requested_check_enabled_check_passed() {
+    let event = MergeGroupEvent {
+        action: MergeGroupEventAction::ChecksRequested,
+        merge_group: MergeGroupEventMergeGroup {
+            base_sha: "base_sha".to_string(),
+            head_commit: MergeGroupHeadCommit {
+                id: "head_sha".to_string(),
+            },
+        },
+        installation: Installation { id: 1 },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            check_merge_group: Some(true),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::MergeGroup(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn merge_group_checks_requested_check_enabled_check_failed() {
+    let event = MergeGroupEvent {
+        action: MergeGroupEventAction::ChecksRequested,
+        merge_group: MergeGroupEventMergeGroup {
+            base_sha: "base_sha".to_string(),
+            head_commit: MergeGroupHeadCommit {
+                id: "head_sha".to_string(),
+            },
+        },
+        installation: Installation { id: 1 },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            check_merge_group: Some(true),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    sha: "sha1".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client
+        .expect_list_commit_pull_requests()
+        .with(eq(event.ctx()), eq("sha1"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(vec![CommitPullRequest {
+                number: 42,
+                ..Default::default()
+            }])))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains("following pull request: #42.")
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::MergeGroup(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn organization_event_other_action() {
+    let event = OrganizationEvent {
+        action: OrganizationEventAction::Other,
+        installation: Installation { id: 1 },
+        membership: None,
+        organization: Organization {
+            login: "org".to_string(),
+        },
+    };
+
+    let gh_client = MockGHClient::new();
+
+    process_event(Arc::new(gh_client), &Event::Organization(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn organization_event_member_added_action_membership_cache_invalidated() {
+    let event = OrganizationEvent {
+        action: OrganizationEventAction::MemberAdded,
+        installation: Installation { id: 1 },
+        membership: Some(OrganizationMembership {
+            user: OrganizationMember {
+                login: "user1".to_string(),
+            },
+        }),
+        organization: Organization {
+            login: "org".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_invalidate_organization_member_cache()
+        .with(eq("org"), eq("user1"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(())));
+
+    process_event(Arc::new(gh_client), &Event::Organization(event)).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_other_action() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Other,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let gh_client = MockGHClient::new();
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+#[should_panic(expected = "error getting pull request commits")]
+async fn pull_request_event_opened_action_error_getting_pr_commits() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+#[should_panic(expected = "error getting repository configuration")]
+async fn pull_request_event_opened_action_error_getting_repository_configuration() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Err(anyhow!("test error")))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_invalid_repository_configuration() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        let err = anyhow::Error::new(ConfigError::Parse {
+            message: "unknown variant `strict`".to_string(),
+            line: Some(2),
+            column: Some(9),
+        })
+        .context(ConfigSource {
+            owner: "owner".to_string(),
+            path: ".dco.yml".to_string(),
+            repo: ".github".to_string(),
+        });
+        Box::pin(future::ready(Err(err)))
+    });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(Comparison::default()))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.actions().is_empty()
+                && check_run.conclusion() == Some(&CheckRunConclusion::Failure)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run
+                    .summary()
+                    .contains("configuration file (`.dco.yml` in `owner/.github`) is not valid")
+                && check_run.summary().contains("line 2, column 9: unknown variant `strict`")
+                && check_run.title() == CHECK_INVALID_CONFIG_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_invalid_repository_configuration_fix_previewed() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        let err = anyhow::Error::new(ConfigError::Parse {
+            message: "unknown variant `strict`".to_string(),
+            line: Some(2),
+            column: Some(9),
+        })
+        .context(ConfigSource {
+            owner: "owner".to_string(),
+            path: ".github/dco.yml".to_string(),
+            repo: "repo".to_string(),
+        });
+        Box::pin(future::ready(Err(err)))
+    });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    ..Default::default()
+                }],
+                files: vec![".github/dco.yml".to_string()],
+            })))
+        });
+    gh_client
+        .expect_get_config_at()
+        .with(eq(event.ctx()), eq("head_sha"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(Some(Config {
+                mode: Some(ConfigMode::Advisory),
+                sources: vec![ConfigSource {
+                    owner: "owner".to_string(),
+                    path: ".github/dco.yml".to_string(),
+                    repo: "repo".to_string(),
+                }],
+                ..Default::default()
+            }))))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.actions().is_empty()
+                && check_run.conclusion() == Some(&CheckRunConclusion::Failure)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains("line 2, column 9: unknown variant `strict`")
+                && check_run.summary().contains("## Configuration preview")
+                && check_run
+                    .summary()
+                    .contains("modifies the configuration file (`.github/dco.yml` in `owner/repo`)")
+                && check_run.summary().contains("| `mode` | `advisory` |")
+                && check_run.summary().contains(
+                    "There is **one commit** in this pull request that would **not pass** the check",
+                )
+                && check_run.title() == CHECK_INVALID_CONFIG_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+#[should_panic(expected = "error checking organization membership")]
+async fn pull_request_event_opened_action_error_checking_user_organization_membership() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: Some(Organization {
+            login: "org".to_string(),
+        }),
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        login: Some("user1".to_string()),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        login: Some("user1".to_string()),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    verified: Some(true),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            require: Some(ConfigRequire { members: Some(false) }),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_is_organization_member()
+        .with(eq(event.ctx()), eq("org"), eq("user1"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+#[should_panic(expected = "error creating check run")]
+async fn pull_request_event_opened_action_error_creating_check_run() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(None))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+#[should_panic(expected = "error updating check run")]
+async fn pull_request_event_opened_action_error_updating_check_run() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(None))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_passed() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed_base_branch_config() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            use_base_branch_config: Some(true),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_get_config_at()
+        .with(eq(event.ctx()), eq("base_sha"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(Some(Config {
+                mode: Some(ConfigMode::Advisory),
+                ..Default::default()
+            }))))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Neutral)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_ADVISORY_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_config_modified_preview() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_get_config_at()
+        .with(eq(event.ctx()), eq("head_sha"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(Some(Config {
+                mode: Some(ConfigMode::Advisory),
+                ..Default::default()
+            }))))
+        });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                files: vec![".github/dco.yml".to_string()],
+            })))
+        });
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+                && check_run.summary().contains("## Configuration preview")
+                && check_run.summary().contains("| `mode` | `advisory` |")
+                && check_run.summary().contains("All commits in this pull request would **pass** the check")
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_config_modified_preview_file_not_used() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_get_config_at()
+        .with(eq(event.ctx()), eq("head_sha"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(Some(Config {
+                mode: Some(ConfigMode::Advisory),
+                sources: vec![ConfigSource {
+                    owner: "owner".to_string(),
+                    path: ".github/dco.yml".to_string(),
+                    repo: "repo".to_string(),
+                }],
+                ..Default::default()
+            }))))
+        });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                files: vec![".dco.yml".to_string()],
+            })))
+        });
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+                && check_run.summary().contains("## Configuration preview")
+                && check_run.summary().contains(
+                    "modifies `.dco.yml`, but it is not used, as `.github/dco.yml` in `owner/repo` takes precedence"
+                )
+                && check_run.summary().contains("| `mode` | `advisory` |")
+                && check_run.summary().contains("All commits in this pull request would **pass** the check")
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_config_modified_preview_invalid_config() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_get_config_at()
+        .with(eq(event.ctx()), eq("head_sha"))
+        .times(1)
+        .returning(|_, _| {
+            let err = anyhow::Error::new(ConfigError::Validation(vec![
+                "invalid pattern `src/[` in `exemptPaths`: unclosed character class; missing ']'".to_string(),
+            ]))
+            .context(ConfigSource {
+                owner: "owner".to_string(),
+                path: ".github/dco.yaml".to_string(),
+                repo: "repo".to_string(),
+            });
+            Box::pin(future::ready(Err(err)))
+        });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                files: vec![".github/dco.yaml".to_string()],
+            })))
+        });
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+                && check_run.summary().contains("## Configuration preview")
+                && check_run
+                    .summary()
+                    .contains("modifies the configuration file (`.github/dco.yaml` in `owner/repo`)")
+                && check_run.summary().contains("The new configuration is **not valid**")
+                && check_run.summary().contains("invalid pattern `src/[` in `exemptPaths`")
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_passed_author_is_member() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: Some(Organization {
+            login: "org".to_string(),
+        }),
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        login: Some("user1".to_string()),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        login: Some("user1".to_string()),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    verified: Some(true),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            require: Some(ConfigRequire { members: Some(false) }),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_is_organization_member()
+        .with(eq(event.ctx()), eq("org"), eq("user1"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(true))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed_author_is_not_member() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: Some(Organization {
+            login: "org".to_string(),
+        }),
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        login: Some("user1".to_string()),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        login: Some("user1".to_string()),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    verified: Some(true),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            require: Some(ConfigRequire { members: Some(false) }),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_is_organization_member()
+        .with(eq(event.ctx()), eq("org"), eq("user1"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(false))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_passed_author_is_bot_membership_check_skipped() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: Some(Organization {
+            login: "org".to_string(),
+        }),
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "bot".to_string(),
+                        email: "bot@email.test".to_string(),
+                        is_bot: true,
+                        login: Some("bot".to_string()),
+                    }),
+                    committer: Some(User {
+                        name: "bot".to_string(),
+                        email: "bot@email.test".to_string(),
+                        is_bot: true,
+                        login: Some("bot".to_string()),
+                    }),
+                    message: "Test commit message".to_string(),
+                    verified: Some(true),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            require: Some(ConfigRequire { members: Some(false) }),
+            ..Default::default()
+        }))))
+    });
+    gh_client.expect_is_organization_member().times(0);
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_passed_author_membership_checked_once() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: Some(Organization {
+            login: "org".to_string(),
+        }),
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![
+                    Commit {
+                        author: Some(User {
+                            name: "user1".to_string(),
+                            email: "user1@email.test".to_string(),
+                            login: Some("user1".to_string()),
+                            ..Default::default()
+                        }),
+                        committer: Some(User {
+                            name: "user1".to_string(),
+                            email: "user1@email.test".to_string(),
+                            login: Some("user1".to_string()),
+                            ..Default::default()
+                        }),
+                        message: "Test commit message".to_string(),
+                        verified: Some(true),
+                        ..Default::default()
+                    },
+                    Commit {
+                        author: Some(User {
+                            name: "user1".to_string(),
+                            email: "user1@email.test".to_string(),
+                            login: Some("user1".to_string()),
+                            ..Default::default()
+                        }),
+                        committer: Some(User {
+                            name: "user1".to_string(),
+                            email: "user1@email.test".to_string(),
+                            login: Some("user1".to_string()),
+                            ..Default::default()
+                        }),
+                        message: "Test commit message".to_string(),
+                        verified: Some(true),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            require: Some(ConfigRequire { members: Some(false) }),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_is_organization_member()
+        .with(eq(event.ctx()), eq("org"), eq("user1"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(true))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: userx <userx@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed_exempt_paths_overridden_by_policy() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: userx <userx@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                files: vec!["src/main.rs".to_string()],
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        let mut config = Config::from_yaml("exemptPaths: [\"**\"]\n").unwrap().unwrap();
+        config.apply_policy(&ConfigPolicy {
+            exempt_paths: Some(vec![]),
+            ..Default::default()
+        });
+        Box::pin(future::ready(Ok(Some(config))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains("have been overridden by the policy")
+                && check_run.summary().contains("`exemptPaths`")
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed_failure_comment_created() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: userx <userx@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            comment_on_failure: Some(true),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+    gh_client
+        .expect_find_issue_comment()
+        .with(eq(event.ctx()), eq(1), eq(FAILURE_COMMENT_MARKER))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(None))));
+    gh_client
+        .expect_create_issue_comment()
+        .withf(|ctx, issue_number, body| {
+            ctx.owner == "owner"
+                && *issue_number == 1
+                && body.starts_with(FAILURE_COMMENT_MARKER)
+                && body.contains("the DCO check **did not pass**")
+                && body.contains("How to fix missing or invalid sign-offs")
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed_failure_comment_updated() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: userx <userx@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            comment_on_failure: Some(true),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+    gh_client
+        .expect_find_issue_comment()
+        .with(eq(event.ctx()), eq(1), eq(FAILURE_COMMENT_MARKER))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(Some(42)))));
+    gh_client
+        .expect_update_issue_comment()
+        .withf(|ctx, comment_id, body| {
+            ctx.owner == "owner"
+                && *comment_id == 42
+                && body.starts_with(FAILURE_COMMENT_MARKER)
+                && body.contains("the DCO check **did not pass**")
+                && body.contains("How to fix missing or invalid sign-offs")
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_passed_failure_comment_deleted() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            comment_on_failure: Some(true),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+    gh_client
+        .expect_find_issue_comment()
+        .with(eq(event.ctx()), eq(1), eq(FAILURE_COMMENT_MARKER))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(Some(42)))));
+    gh_client
+        .expect_delete_issue_comment()
+        .with(eq(event.ctx()), eq(42))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed_failure_label_added() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: userx <userx@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            failure_label: Some("dco: missing".to_string()),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    gh_client
+        .expect_create_label_if_missing()
+        .with(
+            eq(event.ctx()),
+            eq("dco: missing"),
+            eq(FAILURE_LABEL_COLOR),
+            eq(FAILURE_LABEL_DESCRIPTION),
+        )
+        .times(1)
+        .returning(|_, _, _, _| Box::pin(future::ready(Ok(()))));
+    gh_client
+        .expect_add_issue_label()
+        .with(eq(event.ctx()), eq(1), eq("dco: missing"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed_error_adding_failure_label() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: userx <userx@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            failure_label: Some("dco: missing".to_string()),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    gh_client
+        .expect_create_label_if_missing()
+        .with(
+            eq(event.ctx()),
+            eq("dco: missing"),
+            eq(FAILURE_LABEL_COLOR),
+            eq(FAILURE_LABEL_DESCRIPTION),
+        )
+        .times(1)
+        .returning(|_, _, _, _| Box::pin(future::ready(Ok(()))));
+    gh_client
+        .expect_add_issue_label()
+        .with(eq(event.ctx()), eq(1), eq("dco: missing"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+
+    let err = process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event)))
+        .await
+        .unwrap_err();
+    assert!(err.downcast_ref::<CheckResultPublished>().is_some());
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed_failure_label_already_present() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![Label {
+                name: "dco: missing".to_string(),
+            }],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: userx <userx@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            failure_label: Some("dco: missing".to_string()),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_passed_failure_label_removed() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![Label {
+                name: "dco: missing".to_string(),
+            }],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            failure_label: Some("dco: missing".to_string()),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    gh_client
+        .expect_remove_issue_label()
+        .with(eq(event.ctx()), eq(1), eq("dco: missing"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed_reported_as_commit_status() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_commit_status()
+        .withf(move |ctx, commit_status| {
+            *ctx == expected_ctx
+                && commit_status.context() == CHECK_NAME
+                && commit_status.description() == CHECK_IN_PROGRESS_TITLE
+                && commit_status.sha() == "head_sha"
+                && commit_status.state() == &CommitStatusState::Pending
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: userx <userx@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            report_as: Some(ConfigReportAs::CommitStatus),
+            ..Default::default()
+        }))))
+    });
+
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_commit_status()
+        .withf(move |ctx, commit_status| {
+            *ctx == expected_ctx
+                && commit_status.context() == CHECK_NAME
+                && commit_status.description() == "All commits are incorrectly signed off"
+                && commit_status.sha() == "head_sha"
+                && commit_status.state() == &CommitStatusState::Failure
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_passed_reported_as_check_run_and_commit_status() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_commit_status()
+        .withf(move |ctx, commit_status| {
+            *ctx == expected_ctx
+                && commit_status.context() == CHECK_NAME
+                && commit_status.description() == CHECK_IN_PROGRESS_TITLE
+                && commit_status.sha() == "head_sha"
+                && commit_status.state() == &CommitStatusState::Pending
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            report_as: Some(ConfigReportAs::Both),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_commit_status()
+        .withf(move |ctx, commit_status| {
+            *ctx == expected_ctx
+                && commit_status.context() == CHECK_NAME
+                && commit_status.description() == "All commits are signed off"
+                && commit_status.sha() == "head_sha"
+                && commit_status.state() == &CommitStatusState::Success
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed_override_action_disabled() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: userx <userx@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            allow_override_action: Some(false),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_failed_advisory_mode() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: userx <userx@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            mode: Some(ConfigMode::Advisory),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions()
+                    == vec![CheckRunAction {
+                        label: OVERRIDE_ACTION_LABEL.to_string(),
+                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
+                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
+                    }]
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Neutral)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains("advisory mode")
+                && check_run.title() == CHECK_FAILED_ADVISORY_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_passed_base_branch_included() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "release-1.0".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            branches: Some(ConfigBranches {
+                include: Some(vec!["main".to_string(), "release-*".to_string()]),
+                exclude: Some(vec!["gh-pages".to_string()]),
+            }),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_passed_only_exempt_paths_modified() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    sha: "sha1".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            exempt_paths: Some(vec!["docs/**".to_string()]),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_list_commit_files()
+        .with(eq(event.ctx()), eq("sha1"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(vec!["docs/README.md".to_string()]))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains("Skipped: only exempt paths modified (docs/**)")
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_success_check_passed_valid_signoff_in_cherry_picked_commit() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "release-1.0".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            allow_cherry_picks: Some(true),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user2".to_string(),
+                        email: "user2@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    (cherry picked from commit 0123456789abcdef0123456789abcdef01234567)
+                "}
+                    .to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client
+        .expect_get_commit()
+        .with(eq(event.ctx()), eq("0123456789abcdef0123456789abcdef01234567"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(Some(Commit {
+                author: Some(User {
+                    name: "user1".to_string(),
+                    email: "user1@email.test".to_string(),
+                    ..Default::default()
+                }),
+                message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                .to_string(),
+                sha: "0123456789abcdef0123456789abcdef01234567".to_string(),
+                ..Default::default()
+            }))))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains("Valid sign-off found in cherry-picked commit")
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_base_branch_excluded() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "gh-pages".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            branches: Some(ConfigBranches {
+                include: Some(vec!["main".to_string(), "release-*".to_string()]),
+                exclude: Some(vec!["gh-pages".to_string()]),
+            }),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains("`gh-pages` branch")
+                && check_run.title() == CHECK_SKIPPED_BRANCH_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_base_branch_not_included() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "experimental".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            branches: Some(ConfigBranches {
+                include: Some(vec!["main".to_string(), "release-*".to_string()]),
+                exclude: None,
+            }),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains("`experimental` branch")
+                && check_run.title() == CHECK_SKIPPED_BRANCH_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_labeled_action_other_label() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Labeled,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: Some(Label {
+            name: "bug".to_string(),
+        }),
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![Label {
+                name: "bug".to_string(),
+            }],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            exemption_label: Some("dco-exempt".to_string()),
+            ..Default::default()
+        }))))
+    });
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_labeled_action_exemption_label_applied_by_maintainer() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Labeled,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: Some(Label {
+            name: "dco-exempt".to_string(),
+        }),
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![Label {
+                name: "dco-exempt".to_string(),
+            }],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            exemption_label: Some("dco-exempt".to_string()),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_get_repository_permission()
+        .with(eq(event.ctx()), eq("user"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(RepositoryPermission::Maintain))));
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains("@user")
+                && check_run.summary().contains("`dco-exempt` label")
+                && check_run.title() == CHECK_EXEMPTED_LABEL_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_synchronize_action_exemption_label_applied_by_user_without_permission() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Synchronize,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![Label {
+                name: "dco-exempt".to_string(),
+            }],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            exemption_label: Some("dco-exempt".to_string()),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_get_label_applier()
+        .with(eq(event.ctx()), eq(1), eq("dco-exempt"))
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(Some("user2".to_string())))));
+    gh_client
+        .expect_get_repository_permission()
+        .with(eq(event.ctx()), eq("user2"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(RepositoryPermission::Write))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && !check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_unlabeled_action_exemption_label_removed() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Unlabeled,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: Some(Label {
+            name: "dco-exempt".to_string(),
+        }),
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            exemption_label: Some("dco-exempt".to_string()),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && !check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_opened_action_draft_check_deferred() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Opened,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: true,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            defer_drafts: Some(true),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Neutral)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary() == CHECK_DEFERRED_DRAFT_SUMMARY
+                && check_run.title() == CHECK_DEFERRED_DRAFT_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_ready_for_review_action_success_check_failed() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::ReadyForReview,
+        changes: None,
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: None,
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            defer_drafts: Some(true),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "WIP".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && !check_run.actions().is_empty()
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run.title() == CHECK_FAILED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_edited_action_squash_merge_disabled() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Edited,
+        changes: Some(PullRequestEventChanges {
+            body: Some(PullRequestEventChange {
+                from: Some("Previous body".to_string()),
+            }),
+            title: None,
+        }),
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: Some("Signed-off-by: user1 <user1@email.test>".to_string()),
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_edited_action_description_not_changed() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Edited,
+        changes: Some(PullRequestEventChanges {
+            body: None,
+            title: None,
+        }),
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: Some("Signed-off-by: user1 <user1@email.test>".to_string()),
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            squash_merge: Some(true),
+            ..Default::default()
+        }))))
+    });
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn pull_request_event_edited_action_success_check_passed_signoff_in_description() {
+    let event = PullRequestEvent {
+        action: PullRequestEventAction::Edited,
+        changes: Some(PullRequestEventChanges {
+            body: Some(PullRequestEventChange { from: None }),
+            title: None,
+        }),
+        installation: Installation { id: 1 },
+        label: None,
+        organization: None,
+        pull_request: PullRequest {
+            base: PullRequestBase {
+                ref_: "base_ref".to_string(),
+                sha: "base_sha".to_string(),
+            },
+            body: Some("Signed-off-by: user1 <user1@email.test>".to_string()),
+            draft: false,
+            head: PullRequestHead {
+                ref_: "head_ref".to_string(),
+                sha: "head_sha".to_string(),
+            },
+            html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        },
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
     let expected_ctx = event.ctx();
     gh_client
         .expect_create_check_run()
         .withf(move |ctx, check_run| {
             *ctx == expected_ctx
+                && check_run.head_sha() == "head_sha"
+                && check_run.name() == CHECK_NAME
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            squash_merge: Some(true),
+            ..Default::default()
+        }))))
+    });
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
                 && check_run.actions().is_empty()
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::Success
+                && check_run
+                    .completed_at()
+                    .is_some_and(|completed_at| completed_at >= check_run.started_at())
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
                 && check_run.head_sha() == "head_sha"
                 && check_run.name() == CHECK_NAME
                 && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains("pull request description")
                 && check_run.title() == CHECK_PASSED_TITLE
         })
         .times(1)
-        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::PullRequest(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn push_event_config_not_modified() {
+    let event = PushEvent {
+        after: "after_sha".to_string(),
+        before: "before_sha".to_string(),
+        commits: vec![PushEventCommit {
+            added: vec![],
+            modified: vec!["README.md".to_string()],
+            removed: vec![],
+        }],
+        created: false,
+        deleted: false,
+        installation: Installation { id: 1 },
+        organization: None,
+        ref_: "refs/heads/main".to_string(),
+        repository: PushEventRepository {
+            default_branch: "main".to_string(),
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(None))));
+
+    process_event(Arc::new(gh_client), &Event::Push(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn push_event_config_modified_in_other_branch_config_cache_invalidated() {
+    let event = PushEvent {
+        after: "after_sha".to_string(),
+        before: "before_sha".to_string(),
+        commits: vec![PushEventCommit {
+            added: vec![],
+            modified: vec![".github/dco.yml".to_string()],
+            removed: vec![],
+        }],
+        created: false,
+        deleted: false,
+        installation: Installation { id: 1 },
+        organization: None,
+        ref_: "refs/heads/release-1.0".to_string(),
+        repository: PushEventRepository {
+            default_branch: "main".to_string(),
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_invalidate_config_cache()
+        .with(eq("owner"), eq("repo"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(())));
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(None))));
 
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::Push(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-async fn pull_request_event_opened_action_success_check_failed_author_is_not_member() {
-    let event = PullRequestEvent {
-        action: PullRequestEventAction::Opened,
+async fn push_event_config_added_in_default_branch_open_pull_requests_checked() {
+    let event = PushEvent {
+        after: "after_sha".to_string(),
+        before: "before_sha".to_string(),
+        commits: vec![PushEventCommit {
+            added: vec![".dco.yml".to_string()],
+            modified: vec![],
+            removed: vec![],
+        }],
+        created: false,
+        deleted: false,
         installation: Installation { id: 1 },
-        organization: Some(Organization {
-            login: "org".to_string(),
-        }),
-        pull_request: PullRequest {
+        organization: None,
+        ref_: "refs/heads/main".to_string(),
+        repository: PushEventRepository {
+            default_branch: "main".to_string(),
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_invalidate_config_cache()
+        .with(eq("owner"), eq("repo"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(())));
+    gh_client.expect_list_open_pull_requests().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(vec![PullRequest {
             base: PullRequestBase {
-                ref_: "base_ref".to_string(),
+                ref_: "main".to_string(),
                 sha: "base_sha".to_string(),
             },
+            body: None,
+            draft: true,
             head: PullRequestHead {
                 ref_: "head_ref".to_string(),
                 sha: "head_sha".to_string(),
             },
             html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
+        }])))
+    });
+    gh_client
+        .expect_get_config_at()
+        .with(eq(event.ctx()), eq("before_sha"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(None))));
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            defer_drafts: Some(true),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.conclusion() == Some(&CheckRunConclusion::Neutral)
+                && check_run.head_sha() == "head_sha"
+                && check_run.title() == CHECK_DEFERRED_DRAFT_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+
+    process_event(Arc::new(gh_client), &Event::Push(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn push_event_config_added_in_default_branch_open_pull_requests_check_limit_reached() {
+    let event = PushEvent {
+        after: "after_sha".to_string(),
+        before: "before_sha".to_string(),
+        commits: vec![PushEventCommit {
+            added: vec![".dco.yml".to_string()],
+            modified: vec![],
+            removed: vec![],
+        }],
+        created: false,
+        deleted: false,
+        installation: Installation { id: 1 },
+        organization: None,
+        ref_: "refs/heads/main".to_string(),
+        repository: PushEventRepository {
+            default_branch: "main".to_string(),
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
         },
-        repository: Repository {
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_invalidate_config_cache()
+        .with(eq("owner"), eq("repo"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(())));
+    gh_client.expect_list_open_pull_requests().with(eq(event.ctx())).times(1).returning(|_| {
+        let pull_requests = (1..=OPEN_PULL_REQUESTS_CHECK_LIMIT as i64 + 5)
+            .map(|number| PullRequest {
+                base: PullRequestBase {
+                    ref_: "main".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                body: None,
+                draft: true,
+                head: PullRequestHead {
+                    ref_: "head_ref".to_string(),
+                    sha: format!("head_sha{number}"),
+                },
+                html_url: "url".to_string(),
+                labels: vec![],
+                number,
+                title: "Test pull request".to_string(),
+            })
+            .collect();
+        Box::pin(future::ready(Ok(pull_requests)))
+    });
+    gh_client
+        .expect_get_config_at()
+        .with(eq(event.ctx()), eq("before_sha"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(None))));
+    gh_client
+        .expect_get_config()
+        .with(eq(event.ctx()))
+        .times(OPEN_PULL_REQUESTS_CHECK_LIMIT)
+        .returning(|_| {
+            Box::pin(future::ready(Ok(Some(Config {
+                defer_drafts: Some(true),
+                ..Default::default()
+            }))))
+        });
+    gh_client
+        .expect_create_check_run()
+        .withf(|_, check_run| {
+            let number: usize = check_run.head_sha().trim_start_matches("head_sha").parse().unwrap();
+            number <= OPEN_PULL_REQUESTS_CHECK_LIMIT && check_run.title() == CHECK_DEFERRED_DRAFT_TITLE
+        })
+        .times(OPEN_PULL_REQUESTS_CHECK_LIMIT)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+
+    process_event(Arc::new(gh_client), &Event::Push(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn push_event_config_added_in_default_branch_error_listing_open_pull_requests_push_checked() {
+    let event = PushEvent {
+        after: "after_sha".to_string(),
+        before: "before_sha".to_string(),
+        commits: vec![PushEventCommit {
+            added: vec![".dco.yml".to_string()],
+            modified: vec![],
+            removed: vec![],
+        }],
+        created: false,
+        deleted: false,
+        installation: Installation { id: 1 },
+        organization: None,
+        ref_: "refs/heads/main".to_string(),
+        repository: PushEventRepository {
+            default_branch: "main".to_string(),
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_invalidate_config_cache()
+        .with(eq("owner"), eq("repo"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(())));
+    gh_client
+        .expect_list_open_pull_requests()
+        .with(eq(event.ctx()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Err(anyhow!("test error")))));
+    gh_client
+        .expect_get_config_at()
+        .with(eq(event.ctx()), eq("before_sha"))
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(None))));
+
+    let err = process_event(Arc::new(gh_client), &Event::Push(Box::new(event))).await.unwrap_err();
+    assert!(format!("{err:#}").contains("error listing open pull requests"));
+}
+
+#[tokio::test]
+async fn push_event_branch_created_push_not_checked() {
+    let event = PushEvent {
+        after: "after_sha".to_string(),
+        before: "0000000000000000000000000000000000000000".to_string(),
+        commits: vec![PushEventCommit {
+            added: vec![],
+            modified: vec!["README.md".to_string()],
+            removed: vec![],
+        }],
+        created: true,
+        deleted: false,
+        installation: Installation { id: 1 },
+        organization: None,
+        ref_: "refs/heads/main".to_string(),
+        repository: PushEventRepository {
+            default_branch: "main".to_string(),
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let gh_client = MockGHClient::new();
+
+    process_event(Arc::new(gh_client), &Event::Push(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn push_event_push_check_enabled_check_passed() {
+    let event = PushEvent {
+        after: "after_sha".to_string(),
+        before: "before_sha".to_string(),
+        commits: vec![PushEventCommit {
+            added: vec![],
+            modified: vec!["README.md".to_string()],
+            removed: vec![],
+        }],
+        created: false,
+        deleted: false,
+        installation: Installation { id: 1 },
+        organization: None,
+        ref_: "refs/heads/main".to_string(),
+        repository: PushEventRepository {
+            default_branch: "main".to_string(),
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user1".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            check_pushes: Some(ConfigCheckPushes {
+                branches: Some(vec!["main".to_string()]),
+                open_issue: Some(true),
+            }),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "after_sha"
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("before_sha"), eq("after_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: indoc! {r"
+                    Test commit message
+
+                    Signed-off-by: user1 <user1@email.test>
+                "}
+                    .to_string(),
+                    sha: "sha1".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client
+        .expect_list_commit_pull_requests()
+        .with(eq(event.ctx()), eq("sha1"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(vec![CommitPullRequest {
+                merged_at: None,
+                number: 42,
+            }])))
+        });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
+            *ctx == expected_ctx
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run.conclusion() == Some(&CheckRunConclusion::Success)
+                && check_run.head_sha() == "after_sha"
+                && check_run.status() == &CheckRunStatus::Completed
+                && check_run
+                    .summary()
+                    .contains("All commits pushed to the `main` branch are signed off, the check **passed**.")
+                && check_run.title() == CHECK_PASSED_TITLE
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+
+    process_event(Arc::new(gh_client), &Event::Push(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn push_event_push_check_enabled_merged_pull_request_not_checked() {
+    let event = PushEvent {
+        after: "after_sha".to_string(),
+        before: "before_sha".to_string(),
+        commits: vec![PushEventCommit {
+            added: vec![],
+            modified: vec!["README.md".to_string()],
+            removed: vec![],
+        }],
+        created: false,
+        deleted: false,
+        installation: Installation { id: 1 },
+        organization: None,
+        ref_: "refs/heads/main".to_string(),
+        repository: PushEventRepository {
+            default_branch: "main".to_string(),
             name: "repo".to_string(),
             owner: RepositoryOwner {
                 login: "owner".to_string(),
             },
         },
+        sender: Sender {
+            login: "user1".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            check_pushes: Some(ConfigCheckPushes {
+                branches: Some(vec!["main".to_string()]),
+                open_issue: Some(true),
+            }),
+            ..Default::default()
+        }))))
+    });
     gh_client
         .expect_compare_commits()
-        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .with(eq(event.ctx()), eq("before_sha"), eq("after_sha"))
         .times(1)
         .returning(|_, _, _| {
-            Box::pin(future::ready(Ok(vec![Commit {
-                author: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    login: Some("user1".to_string()),
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    sha: "sha1".to_string(),
                     ..Default::default()
-                }),
-                committer: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    login: Some("user1".to_string()),
+                }],
+                ..Default::default()
+            })))
+        });
+    gh_client
+        .expect_list_commit_pull_requests()
+        .with(eq(event.ctx()), eq("sha1"))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(vec![CommitPullRequest {
+                merged_at: Some(Utc::now()),
+                number: 42,
+            }])))
+        });
+
+    process_event(Arc::new(gh_client), &Event::Push(Box::new(event))).await.unwrap();
+}
+
+#[tokio::test]
+async fn push_event_push_check_enabled_check_failed_issue_opened() {
+    let event = PushEvent {
+        after: "after_sha".to_string(),
+        before: "before_sha".to_string(),
+        commits: vec![PushEventCommit {
+            added: vec![],
+            modified: vec!["README.md".to_string()],
+            removed: vec![],
+        }],
+        created: false,
+        deleted: false,
+        installation: Installation { id: 1 },
+        organization: None,
+        ref_: "refs/heads/release-1.0".to_string(),
+        repository: PushEventRepository {
+            default_branch: "main".to_string(),
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user1".to_string(),
+        },
+    };
+
+    let mut gh_client = MockGHClient::new();
+    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
+        Box::pin(future::ready(Ok(Some(Config {
+            check_pushes: Some(ConfigCheckPushes {
+                branches: Some(vec!["main".to_string(), "release-*".to_string()]),
+                open_issue: Some(true),
+            }),
+            ..Default::default()
+        }))))
+    });
+    let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_check_run()
+        .withf(move |ctx, check_run| {
+            *ctx == expected_ctx
+                && check_run.head_sha() == "after_sha"
+                && check_run.status() == &CheckRunStatus::InProgress
+                && check_run.title() == CHECK_IN_PROGRESS_TITLE
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .with(eq(event.ctx()), eq("before_sha"), eq("after_sha"))
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(future::ready(Ok(Comparison {
+                commits: vec![Commit {
+                    author: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    committer: Some(User {
+                        name: "user1".to_string(),
+                        email: "user1@email.test".to_string(),
+                        ..Default::default()
+                    }),
+                    message: "Test commit message".to_string(),
+                    sha: "sha1".to_string(),
                     ..Default::default()
-                }),
-                message: "Test commit message".to_string(),
-                verified: Some(true),
+                }],
                 ..Default::default()
-            }])))
+            })))
         });
-    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
-        Box::pin(future::ready(Ok(Some(Config {
-            require: Some(ConfigRequire { members: Some(false) }),
-            ..Default::default()
-        }))))
-    });
     gh_client
-        .expect_is_organization_member()
-        .with(eq(event.ctx()), eq("org"), eq("user1"))
+        .expect_list_commit_pull_requests()
+        .with(eq(event.ctx()), eq("sha1"))
         .times(1)
-        .returning(|_, _, _| Box::pin(future::ready(Ok(false))));
+        .returning(|_, _| Box::pin(future::ready(Ok(vec![]))));
     let expected_ctx = event.ctx();
     gh_client
-        .expect_create_check_run()
-        .withf(move |ctx, check_run| {
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
             *ctx == expected_ctx
-                && check_run.actions()
-                    == vec![CheckRunAction {
-                        label: OVERRIDE_ACTION_LABEL.to_string(),
-                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
-                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
-                    }]
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::ActionRequired
-                && check_run.head_sha() == "head_sha"
-                && check_run.name() == CHECK_NAME
+                && *check_run_id == 1
+                && check_run.actions().is_empty()
+                && check_run.conclusion() == Some(&CheckRunConclusion::ActionRequired)
+                && check_run.head_sha() == "after_sha"
                 && check_run.status() == &CheckRunStatus::Completed
+                && check_run.summary().contains(
+                    "There is **one commit** incorrectly signed off in the push to the `release-1.0` branch, the check **did not pass**."
+                )
                 && check_run.title() == CHECK_FAILED_TITLE
         })
         .times(1)
-        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+    gh_client
+        .expect_create_issue()
+        .withf(|ctx, title, body| {
+            ctx.repo == "repo"
+                && title == "DCO check failed for commits pushed to `release-1.0`"
+                && body.starts_with(
+                    "There is **one commit** incorrectly signed off in the push by @user1 to the `release-1.0` branch, the DCO check **did not pass**."
+                )
+                && body.contains("### Sign-off not found")
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
 
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+    process_event(Arc::new(gh_client), &Event::Push(Box::new(event))).await.unwrap();
 }
 
 #[tokio::test]
-async fn pull_request_event_opened_action_success_check_passed_author_is_bot_membership_check_skipped() {
+async fn report_error_pull_request_event() {
     let event = PullRequestEvent {
         action: PullRequestEventAction::Opened,
+        changes: None,
         installation: Installation { id: 1 },
-        organization: Some(Organization {
-            login: "org".to_string(),
-        }),
+        label: None,
+        organization: None,
         pull_request: PullRequest {
             base: PullRequestBase {
                 ref_: "base_ref".to_string(),
                 sha: "base_sha".to_string(),
             },
+            body: None,
+            draft: false,
             head: PullRequestHead {
                 ref_: "head_ref".to_string(),
                 sha: "head_sha".to_string(),
             },
             html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
         },
         repository: Repository {
             name: "repo".to_string(),
@@ -828,76 +7286,67 @@ async fn pull_request_event_opened_action_success_check_passed_author_is_bot_mem
                 login: "owner".to_string(),
             },
         },
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
     gh_client
-        .expect_compare_commits()
-        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
+        .expect_get_config()
+        .with(eq(event.ctx()))
         .times(1)
-        .returning(|_, _, _| {
-            Box::pin(future::ready(Ok(vec![Commit {
-                author: Some(User {
-                    name: "bot".to_string(),
-                    email: "bot@email.test".to_string(),
-                    is_bot: true,
-                    login: Some("bot".to_string()),
-                }),
-                committer: Some(User {
-                    name: "bot".to_string(),
-                    email: "bot@email.test".to_string(),
-                    is_bot: true,
-                    login: Some("bot".to_string()),
-                }),
-                message: "Test commit message".to_string(),
-                verified: Some(true),
-                ..Default::default()
-            }])))
-        });
-    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
-        Box::pin(future::ready(Ok(Some(Config {
-            require: Some(ConfigRequire { members: Some(false) }),
-            ..Default::default()
-        }))))
-    });
-    gh_client.expect_is_organization_member().times(0);
+        .returning(|_| Box::pin(future::ready(Ok(None))));
     let expected_ctx = event.ctx();
     gh_client
         .expect_create_check_run()
         .withf(move |ctx, check_run| {
             *ctx == expected_ctx
                 && check_run.actions().is_empty()
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::Success
+                && check_run.conclusion() == Some(&CheckRunConclusion::Failure)
                 && check_run.head_sha() == "head_sha"
                 && check_run.name() == CHECK_NAME
                 && check_run.status() == &CheckRunStatus::Completed
-                && check_run.title() == CHECK_PASSED_TITLE
+                && check_run.summary().contains("An internal error occurred")
+                && check_run.summary().contains("Event delivery id: `delivery_id`")
+                && check_run.title() == CHECK_INTERNAL_ERROR_TITLE
         })
         .times(1)
-        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
 
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+    report_error(
+        Arc::new(gh_client),
+        &Event::PullRequest(Box::new(event)),
+        Some("delivery_id"),
+        &anyhow!("test error"),
+    )
+    .await
+    .unwrap();
 }
 
 #[tokio::test]
-async fn pull_request_event_opened_action_success_check_passed_author_membership_checked_once() {
+async fn report_error_pull_request_event_check_in_progress() {
     let event = PullRequestEvent {
         action: PullRequestEventAction::Opened,
+        changes: None,
         installation: Installation { id: 1 },
-        organization: Some(Organization {
-            login: "org".to_string(),
-        }),
+        label: None,
+        organization: None,
         pull_request: PullRequest {
             base: PullRequestBase {
                 ref_: "base_ref".to_string(),
                 sha: "base_sha".to_string(),
             },
+            body: None,
+            draft: false,
             head: PullRequestHead {
                 ref_: "head_ref".to_string(),
                 sha: "head_sha".to_string(),
             },
             html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
         },
         repository: Repository {
             name: "repo".to_string(),
@@ -905,97 +7354,65 @@ async fn pull_request_event_opened_action_success_check_passed_author_membership
                 login: "owner".to_string(),
             },
         },
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
-    gh_client
-        .expect_compare_commits()
-        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
-        .times(1)
-        .returning(|_, _, _| {
-            Box::pin(future::ready(Ok(vec![
-                Commit {
-                    author: Some(User {
-                        name: "user1".to_string(),
-                        email: "user1@email.test".to_string(),
-                        login: Some("user1".to_string()),
-                        ..Default::default()
-                    }),
-                    committer: Some(User {
-                        name: "user1".to_string(),
-                        email: "user1@email.test".to_string(),
-                        login: Some("user1".to_string()),
-                        ..Default::default()
-                    }),
-                    message: "Test commit message".to_string(),
-                    verified: Some(true),
-                    ..Default::default()
-                },
-                Commit {
-                    author: Some(User {
-                        name: "user1".to_string(),
-                        email: "user1@email.test".to_string(),
-                        login: Some("user1".to_string()),
-                        ..Default::default()
-                    }),
-                    committer: Some(User {
-                        name: "user1".to_string(),
-                        email: "user1@email.test".to_string(),
-                        login: Some("user1".to_string()),
-                        ..Default::default()
-                    }),
-                    message: "Test commit message".to_string(),
-                    verified: Some(true),
-                    ..Default::default()
-                },
-            ])))
-        });
-    gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
-        Box::pin(future::ready(Ok(Some(Config {
-            require: Some(ConfigRequire { members: Some(false) }),
-            ..Default::default()
-        }))))
-    });
-    gh_client
-        .expect_is_organization_member()
-        .with(eq(event.ctx()), eq("org"), eq("user1"))
-        .times(1)
-        .returning(|_, _, _| Box::pin(future::ready(Ok(true))));
     let expected_ctx = event.ctx();
     gh_client
-        .expect_create_check_run()
-        .withf(move |ctx, check_run| {
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
             *ctx == expected_ctx
-                && check_run.actions().is_empty()
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::Success
+                && *check_run_id == 1
+                && check_run.conclusion() == Some(&CheckRunConclusion::Failure)
                 && check_run.head_sha() == "head_sha"
-                && check_run.name() == CHECK_NAME
                 && check_run.status() == &CheckRunStatus::Completed
-                && check_run.title() == CHECK_PASSED_TITLE
+                && check_run.title() == CHECK_INTERNAL_ERROR_TITLE
         })
         .times(1)
-        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
 
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+    let err = anyhow!("test error").context(CheckInProgress {
+        check_run_id: Some(1),
+        config: Config::default(),
+        ctx: event.ctx(),
+        head_sha: "head_sha".to_string(),
+    });
+    report_error(
+        Arc::new(gh_client),
+        &Event::PullRequest(Box::new(event)),
+        None,
+        &err,
+    )
+    .await
+    .unwrap();
 }
 
 #[tokio::test]
-async fn pull_request_event_opened_action_success_check_failed() {
+async fn report_error_pull_request_event_check_result_published() {
     let event = PullRequestEvent {
         action: PullRequestEventAction::Opened,
+        changes: None,
         installation: Installation { id: 1 },
+        label: None,
         organization: None,
         pull_request: PullRequest {
             base: PullRequestBase {
                 ref_: "base_ref".to_string(),
                 sha: "base_sha".to_string(),
             },
+            body: None,
+            draft: false,
             head: PullRequestHead {
                 ref_: "head_ref".to_string(),
                 sha: "head_sha".to_string(),
             },
             html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
         },
         repository: Repository {
             name: "repo".to_string(),
@@ -1003,79 +7420,47 @@ async fn pull_request_event_opened_action_success_check_failed() {
                 login: "owner".to_string(),
             },
         },
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
-    let mut gh_client = MockGHClient::new();
-    gh_client
-        .expect_compare_commits()
-        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
-        .times(1)
-        .returning(|_, _, _| {
-            Box::pin(future::ready(Ok(vec![Commit {
-                author: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    ..Default::default()
-                }),
-                committer: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    ..Default::default()
-                }),
-                message: indoc! {r"
-                    Test commit message
-
-                    Signed-off-by: userx <userx@email.test>
-                "}
-                .to_string(),
-                ..Default::default()
-            }])))
-        });
-    gh_client
-        .expect_get_config()
-        .with(eq(event.ctx()))
-        .times(1)
-        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
-    let expected_ctx = event.ctx();
-    gh_client
-        .expect_create_check_run()
-        .withf(move |ctx, check_run| {
-            *ctx == expected_ctx
-                && check_run.actions()
-                    == vec![CheckRunAction {
-                        label: OVERRIDE_ACTION_LABEL.to_string(),
-                        description: OVERRIDE_ACTION_DESCRIPTION.to_string(),
-                        identifier: OVERRIDE_ACTION_IDENTIFIER.to_string(),
-                    }]
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::ActionRequired
-                && check_run.head_sha() == "head_sha"
-                && check_run.name() == CHECK_NAME
-                && check_run.status() == &CheckRunStatus::Completed
-                && check_run.title() == CHECK_FAILED_TITLE
-        })
-        .times(1)
-        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+    let gh_client = MockGHClient::new();
 
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+    let err = anyhow!("test error").context(CheckResultPublished);
+    report_error(
+        Arc::new(gh_client),
+        &Event::PullRequest(Box::new(event)),
+        None,
+        &err,
+    )
+    .await
+    .unwrap();
 }
 
 #[tokio::test]
-async fn pull_request_event_opened_action_success_check_failed_override_action_disabled() {
+async fn report_error_pull_request_event_reported_as_commit_status() {
     let event = PullRequestEvent {
         action: PullRequestEventAction::Opened,
+        changes: None,
         installation: Installation { id: 1 },
+        label: None,
         organization: None,
         pull_request: PullRequest {
             base: PullRequestBase {
                 ref_: "base_ref".to_string(),
                 sha: "base_sha".to_string(),
             },
+            body: None,
+            draft: false,
             head: PullRequestHead {
                 ref_: "head_ref".to_string(),
                 sha: "head_sha".to_string(),
             },
             html_url: "url".to_string(),
+            labels: vec![],
+            number: 1,
+            title: "Test pull request".to_string(),
         },
         repository: Repository {
             name: "repo".to_string(),
@@ -1083,55 +7468,161 @@ async fn pull_request_event_opened_action_success_check_failed_override_action_d
                 login: "owner".to_string(),
             },
         },
+        sender: Sender {
+            login: "user".to_string(),
+        },
     };
 
     let mut gh_client = MockGHClient::new();
-    gh_client
-        .expect_compare_commits()
-        .with(eq(event.ctx()), eq("base_sha"), eq("head_sha"))
-        .times(1)
-        .returning(|_, _, _| {
-            Box::pin(future::ready(Ok(vec![Commit {
-                author: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    ..Default::default()
-                }),
-                committer: Some(User {
-                    name: "user1".to_string(),
-                    email: "user1@email.test".to_string(),
-                    ..Default::default()
-                }),
-                message: indoc! {r"
-                    Test commit message
-
-                    Signed-off-by: userx <userx@email.test>
-                "}
-                .to_string(),
-                ..Default::default()
-            }])))
-        });
     gh_client.expect_get_config().with(eq(event.ctx())).times(1).returning(|_| {
         Box::pin(future::ready(Ok(Some(Config {
-            allow_override_action: Some(false),
+            report_as: Some(ConfigReportAs::CommitStatus),
             ..Default::default()
         }))))
     });
     let expected_ctx = event.ctx();
+    gh_client
+        .expect_create_commit_status()
+        .withf(move |ctx, commit_status| {
+            *ctx == expected_ctx
+                && commit_status.context() == CHECK_NAME
+                && commit_status.description() == CHECK_INTERNAL_ERROR_TITLE
+                && commit_status.sha() == "head_sha"
+                && commit_status.state() == &CommitStatusState::Failure
+        })
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+
+    report_error(
+        Arc::new(gh_client),
+        &Event::PullRequest(Box::new(event)),
+        None,
+        &anyhow!("test error"),
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn report_error_issue_comment_event() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Created,
+        comment: IssueComment {
+            body: "/dco recheck".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 1,
+            pull_request: Some(IssuePullRequest {
+                html_url: "pr_url".to_string(),
+            }),
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+
+    let gh_client = MockGHClient::new();
+
+    report_error(
+        Arc::new(gh_client),
+        &Event::IssueComment(event),
+        Some("delivery_id"),
+        &anyhow!("test error"),
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn report_error_issue_comment_event_recheck_check_in_progress() {
+    let event = IssueCommentEvent {
+        action: IssueCommentEventAction::Created,
+        comment: IssueComment {
+            body: "/dco recheck".to_string(),
+            html_url: "comment_url".to_string(),
+        },
+        installation: Installation { id: 1 },
+        issue: Issue {
+            number: 111,
+            pull_request: Some(IssuePullRequest {
+                html_url: "pr_url".to_string(),
+            }),
+        },
+        organization: None,
+        repository: Repository {
+            name: "repo".to_string(),
+            owner: RepositoryOwner {
+                login: "owner".to_string(),
+            },
+        },
+        sender: Sender {
+            login: "user".to_string(),
+        },
+    };
+    let expected_ctx = event.ctx();
+    let event = Event::IssueComment(event);
+
+    let mut gh_client = MockGHClient::new();
+    gh_client
+        .expect_get_config()
+        .with(eq(expected_ctx.clone()))
+        .times(1)
+        .returning(|_| Box::pin(future::ready(Ok(Some(Config::default())))));
+    gh_client
+        .expect_get_pull_request()
+        .with(eq(expected_ctx.clone()), eq(111))
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(future::ready(Ok(PullRequest {
+                base: PullRequestBase {
+                    ref_: "base_ref".to_string(),
+                    sha: "base_sha".to_string(),
+                },
+                body: None,
+                draft: false,
+                head: PullRequestHead {
+                    ref_: "head_ref".to_string(),
+                    sha: "head_sha".to_string(),
+                },
+                html_url: "pr_url".to_string(),
+                labels: vec![],
+                number: 111,
+                title: "Test pull request".to_string(),
+            })))
+        });
     gh_client
         .expect_create_check_run()
-        .withf(move |ctx, check_run| {
+        .withf(|_, check_run| check_run.status() == &CheckRunStatus::InProgress)
+        .times(1)
+        .returning(|_, _| Box::pin(future::ready(Ok(1))));
+    gh_client
+        .expect_compare_commits()
+        .times(1)
+        .returning(|_, _, _| Box::pin(future::ready(Err(anyhow!("test error")))));
+    gh_client
+        .expect_update_check_run()
+        .withf(move |ctx, check_run_id, check_run| {
             *ctx == expected_ctx
-                && check_run.actions().is_empty()
-                && check_run.completed_at() >= check_run.started_at()
-                && check_run.conclusion() == &CheckRunConclusion::ActionRequired
+                && *check_run_id == 1
+                && check_run.conclusion() == Some(&CheckRunConclusion::Failure)
                 && check_run.head_sha() == "head_sha"
-                && check_run.name() == CHECK_NAME
                 && check_run.status() == &CheckRunStatus::Completed
-                && check_run.title() == CHECK_FAILED_TITLE
+                && check_run.summary().contains("delivery_id")
+                && check_run.title() == CHECK_INTERNAL_ERROR_TITLE
         })
         .times(1)
-        .returning(|_, _| Box::pin(future::ready(Ok(()))));
+        .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+    let gh_client: DynGHClient = Arc::new(gh_client);
 
-    process_event(Arc::new(gh_client), &Event::PullRequest(event)).await.unwrap();
+    let err = process_event(gh_client.clone(), &event).await.unwrap_err();
+    report_error(gh_client, &event, Some("delivery_id"), &err).await.unwrap();
 }
@@ -1,4 +1,4 @@
 mod check;
 mod event;
 
-pub use event::process_event;
+pub use event::{process_event, report_error};
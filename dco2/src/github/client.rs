@@ -1,37 +1,138 @@
 //! This module defines an abstraction layer over the GitHub API.
 
-use std::sync::Arc;
+use std::{
+    fmt::Display,
+    sync::{Arc, LazyLock},
+};
 
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, bail};
 use async_trait::async_trait;
 use base64::{Engine as _, engine::general_purpose::STANDARD as b64};
-use cached::cached;
-use chrono::{DateTime, Utc};
+use cached::{Cached, cached};
+use chrono::{DateTime, NaiveDate, Utc};
+use globset::Glob;
 use http::StatusCode;
 #[cfg(test)]
 use mockall::automock;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use tracing::warn;
 
-/// Path of the configuration file in the repository.
-const CONFIG_FILE_PATH: &str = ".github/dco.yml";
+use crate::github::PullRequest;
+
+/// Paths where the configuration file can be located in the repository (in
+/// order of precedence).
+pub const CONFIG_FILE_PATHS: [&str; 3] = [".github/dco.yml", ".github/dco.yaml", ".dco.yml"];
+
+/// Name of the repository holding the organization-wide configuration.
+const ORG_CONFIG_REPO: &str = ".github";
+
+/// Default GitHub API host.
+const DEFAULT_API_HOST: &str = "https://api.github.com";
+
+/// User agent used in the requests to the GitHub API.
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// HTTP client used for the requests to the GitHub API not made through
+/// octorust.
+static HTTP_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(reqwest::Client::new);
 
 /// Abstraction layer over a GitHub client. This trait defines the methods that
 /// a GHClient implementation must provide.
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait GHClient {
-    /// Compare two commits.
-    async fn compare_commits(&self, ctx: &Ctx, base_sha: &str, head_sha: &str) -> Result<Vec<Commit>>;
+    /// Add a label to an issue or pull request.
+    async fn add_issue_label(&self, ctx: &Ctx, issue_number: i64, label: &str) -> Result<()>;
+
+    /// Compare two commits, returning the commits and files modified between
+    /// them.
+    async fn compare_commits(&self, ctx: &Ctx, base_sha: &str, head_sha: &str) -> Result<Comparison>;
+
+    /// Create a check run, returning its id.
+    async fn create_check_run(&self, ctx: &Ctx, check_run: &CheckRun) -> Result<i64>;
+
+    /// Create a commit status.
+    async fn create_commit_status(&self, ctx: &Ctx, commit_status: &CommitStatus) -> Result<()>;
 
-    /// Create a check run.
-    async fn create_check_run(&self, ctx: &Ctx, check_run: &CheckRun) -> Result<()>;
+    /// Create a comment in an issue or pull request.
+    async fn create_issue_comment(&self, ctx: &Ctx, issue_number: i64, body: &str) -> Result<()>;
 
-    /// Get configuration.
+    /// Create an issue in the repository.
+    async fn create_issue(&self, ctx: &Ctx, title: &str, body: &str) -> Result<()>;
+
+    /// Create a label in the repository if it does not exist yet.
+    async fn create_label_if_missing(
+        &self,
+        ctx: &Ctx,
+        label: &str,
+        color: &str,
+        description: &str,
+    ) -> Result<()>;
+
+    /// Delete a comment in an issue or pull request.
+    async fn delete_issue_comment(&self, ctx: &Ctx, comment_id: i64) -> Result<()>;
+
+    /// Find a comment created by a bot in an issue or pull request whose body
+    /// contains the marker provided, returning its id (if any).
+    async fn find_issue_comment(&self, ctx: &Ctx, issue_number: i64, marker: &str) -> Result<Option<i64>>;
+
+    /// Get a commit (if it exists in the repository).
+    async fn get_commit(&self, ctx: &Ctx, sha: &str) -> Result<Option<Commit>>;
+
+    /// Get configuration, merged with the configuration it extends (or the
+    /// organization-wide one, when available).
     async fn get_config(&self, ctx: &Ctx) -> Result<Option<Config>>;
 
+    /// Get the repository configuration at the commit provided (i.e. a pull
+    /// request base or head commit), merged like in `get_config`.
+    async fn get_config_at(&self, ctx: &Ctx, sha: &str) -> Result<Option<Config>>;
+
+    /// Get the login of the user who last applied the label provided to an
+    /// issue or pull request (if it can be found).
+    async fn get_label_applier(&self, ctx: &Ctx, issue_number: i64, label: &str) -> Result<Option<String>>;
+
+    /// Get a pull request.
+    async fn get_pull_request(&self, ctx: &Ctx, number: i64) -> Result<PullRequest>;
+
+    /// Get the permission a user has on the repository.
+    async fn get_repository_permission(&self, ctx: &Ctx, username: &str) -> Result<RepositoryPermission>;
+
+    /// Invalidate the cached configuration of the repository provided (the
+    /// one from its default branch).
+    async fn invalidate_config_cache(&self, owner: &str, repo: &str);
+
+    /// Invalidate the cached membership of the user in the organization.
+    async fn invalidate_organization_member_cache(&self, org: &str, login: &str);
+
     /// Check if a user is a member of the organization.
     async fn is_organization_member(&self, ctx: &Ctx, org: &str, login: &str) -> Result<bool>;
+
+    /// Check if a user is an active member of the organization's team.
+    async fn is_team_member(&self, ctx: &Ctx, org: &str, team_slug: &str, login: &str) -> Result<bool>;
+
+    /// List the files modified by a commit.
+    async fn list_commit_files(&self, ctx: &Ctx, sha: &str) -> Result<Vec<String>>;
+
+    /// List the pull requests associated with a commit.
+    async fn list_commit_pull_requests(&self, ctx: &Ctx, sha: &str) -> Result<Vec<CommitPullRequest>>;
+
+    /// List the open pull requests in the repository, most recently updated
+    /// first.
+    async fn list_open_pull_requests(&self, ctx: &Ctx) -> Result<Vec<PullRequest>>;
+
+    /// Purge all the cached data (configurations and memberships) related to
+    /// the account provided.
+    async fn purge_account_cache(&self, account: &str);
+
+    /// Remove a label from an issue or pull request.
+    async fn remove_issue_label(&self, ctx: &Ctx, issue_number: i64, label: &str) -> Result<()>;
+
+    /// Update an existing check run.
+    async fn update_check_run(&self, ctx: &Ctx, check_run_id: i64, check_run: &CheckRun) -> Result<()>;
+
+    /// Update a comment in an issue or pull request.
+    async fn update_issue_comment(&self, ctx: &Ctx, comment_id: i64, body: &str) -> Result<()>;
 }
 
 /// Type alias to represent a GHClient trait object.
@@ -42,11 +143,13 @@ pub type DynGHClient = Arc<dyn GHClient + Send + Sync>;
 pub struct GHClientOctorust {
     api_host: Option<String>,
     app_credentials: octorust::auth::JWTCredentials,
+    policy: ConfigPolicy,
 }
 
 impl GHClientOctorust {
-    /// Create a new GHClientOctorust instance.
-    pub fn new(cfg: &AppConfig) -> Result<Self> {
+    /// Create a new GHClientOctorust instance. The policy provided is
+    /// enforced over all the repositories configurations.
+    pub fn new(cfg: &AppConfig, policy: ConfigPolicy) -> Result<Self> {
         // Setup credentials
         let private_key = pem::parse(&cfg.private_key)?.contents().to_owned();
         let app_credentials = octorust::auth::JWTCredentials::new(cfg.app_id, private_key)?;
@@ -54,18 +157,72 @@ impl GHClientOctorust {
         Ok(Self {
             api_host: cfg.api_host.clone(),
             app_credentials,
+            policy,
         })
     }
 
+    /// Enforce the policy over the configuration provided. When there is no
+    /// configuration and a policy has been defined, the default configuration
+    /// is used as the base.
+    fn enforce_policy(&self, config: Option<Config>) -> Option<Config> {
+        if self.policy == ConfigPolicy::default() {
+            return config;
+        }
+        let config = match config {
+            Some(mut config) => {
+                config.apply_policy(&self.policy);
+                config
+            }
+            None => {
+                // Settings in the default configuration were not set by the
+                // repository, so they are not reported as overridden
+                let mut config = Config::default();
+                config.apply_policy(&self.policy);
+                config.policy_overrides.clear();
+                config
+            }
+        };
+        Some(config)
+    }
+
+    /// Get an access token for the installation id provided.
+    async fn get_installation_token(&self, inst_id: i64) -> Result<String> {
+        // Setup client authenticated as the app
+        let credentials = octorust::auth::Credentials::JWT(self.app_credentials.clone());
+        let mut client = octorust::Client::new(USER_AGENT, credentials)?;
+        if let Some(api_host) = &self.api_host {
+            client.with_host_override(api_host);
+        }
+
+        create_installation_token(&client, inst_id).await
+    }
+
+    /// Send a GET request to the GitHub API path provided, authenticated as
+    /// the installation provided. This is used for the endpoints whose
+    /// responses octorust is not able to deserialize.
+    async fn raw_get(&self, inst_id: i64, path: &str, query: &[(&str, String)]) -> Result<reqwest::Response> {
+        let token = self.get_installation_token(inst_id).await?;
+        let api_host = self.api_host.as_deref().unwrap_or(DEFAULT_API_HOST);
+        let resp = HTTP_CLIENT
+            .get(format!("{api_host}{path}"))
+            .query(query)
+            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+            .header(reqwest::header::USER_AGENT, USER_AGENT)
+            .bearer_auth(token)
+            .send()
+            .await?;
+
+        Ok(resp)
+    }
+
     /// Setup a new GitHub client for the installation id provided.
     fn setup_client(&self, inst_id: i64) -> Result<octorust::Client> {
         // Setup credentials
-        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
         let tg = octorust::auth::InstallationTokenGenerator::new(inst_id, self.app_credentials.clone());
         let credentials = octorust::auth::Credentials::InstallationToken(tg);
 
         // Setup client
-        let mut client = octorust::Client::new(user_agent, credentials)?;
+        let mut client = octorust::Client::new(USER_AGENT, credentials)?;
         if let Some(api_host) = &self.api_host {
             client.with_host_override(api_host);
         }
@@ -76,36 +233,55 @@ impl GHClientOctorust {
 
 #[async_trait]
 impl GHClient for GHClientOctorust {
+    /// [GHClient::add_issue_label]
+    async fn add_issue_label(&self, ctx: &Ctx, issue_number: i64, label: &str) -> Result<()> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Add label
+        let body = octorust::types::IssuesAddLabelsRequestOneOf::IssuesAddLabelsRequest(
+            octorust::types::IssuesAddLabelsRequest {
+                labels: vec![label.to_string()],
+            },
+        );
+        client.issues().add_labels(&ctx.owner, &ctx.repo, issue_number, &body).await?;
+
+        Ok(())
+    }
+
     /// [GHClient::compare_commits]
-    async fn compare_commits(&self, ctx: &Ctx, base_sha: &str, head_sha: &str) -> Result<Vec<Commit>> {
+    async fn compare_commits(&self, ctx: &Ctx, base_sha: &str, head_sha: &str) -> Result<Comparison> {
         // Setup client for installation provided
         let client = self.setup_client(ctx.inst_id)?;
 
         // Compare commits
         let basehead = format!("{}...{}", base_sha, head_sha);
-        let commits = client
-            .repos()
-            .compare_commits(&ctx.owner, &ctx.repo, 0, 0, &basehead)
-            .await?
-            .body
-            .commits
+        let comparison = client.repos().compare_commits(&ctx.owner, &ctx.repo, 0, 0, &basehead).await?.body;
+
+        // Renamed files are included with their previous name as well
+        let files = comparison
+            .files
             .into_iter()
-            .map(Into::into)
+            .flat_map(|f| [f.filename, f.previous_filename])
+            .filter(|filename| !filename.is_empty())
             .collect();
 
-        Ok(commits)
+        Ok(Comparison {
+            commits: comparison.commits.into_iter().map(Into::into).collect(),
+            files,
+        })
     }
 
     /// [GHClient::create_check_run]
-    async fn create_check_run(&self, ctx: &Ctx, check_run: &CheckRun) -> Result<()> {
+    async fn create_check_run(&self, ctx: &Ctx, check_run: &CheckRun) -> Result<i64> {
         // Setup client for installation provided
         let client = self.setup_client(ctx.inst_id)?;
 
         // Create check run
         let body = octorust::types::ChecksCreateRequest {
             actions: check_run.actions.iter().cloned().map(Into::into).collect(),
-            completed_at: Some(check_run.completed_at),
-            conclusion: Some(check_run.conclusion.clone().into()),
+            completed_at: check_run.completed_at,
+            conclusion: check_run.conclusion.clone().map(Into::into),
             details_url: String::new(),
             external_id: String::new(),
             head_sha: check_run.head_sha.clone(),
@@ -120,86 +296,593 @@ impl GHClient for GHClientOctorust {
             started_at: Some(check_run.started_at),
             status: Some(check_run.status.clone().into()),
         };
-        client.checks().create(&ctx.owner, &ctx.repo, &body).await?;
+        let check_run_id = client.checks().create(&ctx.owner, &ctx.repo, &body).await?.body.id;
+
+        Ok(check_run_id)
+    }
+
+    /// [GHClient::create_commit_status]
+    async fn create_commit_status(&self, ctx: &Ctx, commit_status: &CommitStatus) -> Result<()> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Create commit status
+        let body = octorust::types::ReposCreateCommitStatusRequest {
+            context: commit_status.context.clone(),
+            description: commit_status.description.clone(),
+            state: commit_status.state.clone().into(),
+            target_url: String::new(),
+        };
+        client
+            .repos()
+            .create_commit_status(&ctx.owner, &ctx.repo, &commit_status.sha, &body)
+            .await?;
+
+        Ok(())
+    }
+
+    /// [GHClient::create_issue_comment]
+    async fn create_issue_comment(&self, ctx: &Ctx, issue_number: i64, body: &str) -> Result<()> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Create comment
+        let body = octorust::types::PullsUpdateReviewRequest {
+            body: body.to_string(),
+        };
+        client.issues().create_comment(&ctx.owner, &ctx.repo, issue_number, &body).await?;
+
+        Ok(())
+    }
+
+    /// [GHClient::create_issue]
+    async fn create_issue(&self, ctx: &Ctx, title: &str, body: &str) -> Result<()> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Create issue
+        let body = octorust::types::IssuesCreateRequest {
+            assignee: String::new(),
+            assignees: vec![],
+            body: body.to_string(),
+            labels: vec![],
+            milestone: None,
+            title: octorust::types::TitleOneOf::String(title.to_string()),
+        };
+        client.issues().create(&ctx.owner, &ctx.repo, &body).await?;
 
         Ok(())
     }
 
+    /// [GHClient::create_label_if_missing]
+    async fn create_label_if_missing(
+        &self,
+        ctx: &Ctx,
+        label: &str,
+        color: &str,
+        description: &str,
+    ) -> Result<()> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Check if the label already exists
+        match client.issues().get_label(&ctx.owner, &ctx.repo, label).await {
+            Ok(_) => return Ok(()),
+            Err(octorust::ClientError::HttpError {
+                status,
+                headers: _,
+                error,
+            }) => {
+                if status != StatusCode::NOT_FOUND {
+                    bail!(error);
+                }
+            }
+            Err(err) => bail!(err),
+        }
+
+        // Create label (it may have been created concurrently)
+        let body = octorust::types::IssuesCreateLabelRequest {
+            color: color.to_string(),
+            description: description.to_string(),
+            name: label.to_string(),
+        };
+        match client.issues().create_label(&ctx.owner, &ctx.repo, &body).await {
+            Ok(_) => Ok(()),
+            Err(octorust::ClientError::HttpError {
+                status,
+                headers: _,
+                error,
+            }) => {
+                if status == StatusCode::UNPROCESSABLE_ENTITY {
+                    return Ok(());
+                }
+                bail!(error);
+            }
+            Err(err) => bail!(err),
+        }
+    }
+
+    /// [GHClient::delete_issue_comment]
+    async fn delete_issue_comment(&self, ctx: &Ctx, comment_id: i64) -> Result<()> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Delete comment (it may have been deleted already)
+        match client.issues().delete_comment(&ctx.owner, &ctx.repo, comment_id).await {
+            Ok(_) => Ok(()),
+            Err(octorust::ClientError::HttpError {
+                status,
+                headers: _,
+                error,
+            }) => {
+                if status == StatusCode::NOT_FOUND {
+                    return Ok(());
+                }
+                bail!(error);
+            }
+            Err(err) => bail!(err),
+        }
+    }
+
+    /// [GHClient::find_issue_comment]
+    async fn find_issue_comment(&self, ctx: &Ctx, issue_number: i64, marker: &str) -> Result<Option<i64>> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Look for the first comment created by a bot that contains the marker
+        let comments =
+            client.issues().list_all_comments(&ctx.owner, &ctx.repo, issue_number, None).await?.body;
+        let comment_id = comments
+            .into_iter()
+            .find(|c| c.user.as_ref().is_some_and(|u| u.type_ == "Bot") && c.body.contains(marker))
+            .map(|c| c.id);
+
+        Ok(comment_id)
+    }
+
+    /// [GHClient::get_commit]
+    async fn get_commit(&self, ctx: &Ctx, sha: &str) -> Result<Option<Commit>> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Get commit
+        let resp = match client.repos().get_commit(&ctx.owner, &ctx.repo, 0, 0, sha).await {
+            Ok(resp) => resp,
+            Err(octorust::ClientError::HttpError {
+                status,
+                headers: _,
+                error,
+            }) => {
+                if status == StatusCode::NOT_FOUND || status == StatusCode::UNPROCESSABLE_ENTITY {
+                    return Ok(None);
+                }
+                bail!(error);
+            }
+            Err(err) => bail!(err),
+        };
+
+        Ok(Some(resp.body.into()))
+    }
+
     /// [GHClient::get_config]
     async fn get_config(&self, ctx: &Ctx) -> Result<Option<Config>> {
-        #[cached(
-            max_size = 1000,
-            ttl = 3600,
-            sync_writes = "default",
-            key = "String",
-            convert = r#"{ format!("{}-{}", ctx.owner, ctx.repo) }"#
-        )]
-        async fn inner(client: &octorust::Client, ctx: &Ctx) -> Result<Option<Config>> {
-            // Get configuration file content
-            let resp =
-                match client.repos().get_content_file(&ctx.owner, &ctx.repo, CONFIG_FILE_PATH, "").await {
-                    Ok(resp) => resp,
-                    Err(octorust::ClientError::HttpError {
-                        status,
-                        headers: _,
-                        error,
-                    }) => {
-                        if status == StatusCode::NOT_FOUND {
-                            return Ok(None);
-                        }
-                        bail!(error);
-                    }
-                    Err(err) => bail!(err),
-                };
-
-            // Decode and parse configuration
-            let mut b64data = resp.body.content.as_bytes().to_owned();
-            b64data.retain(|b| !b" \n\t\r\x0b\x0c".contains(b));
-            let data = String::from_utf8(b64.decode(b64data)?)?;
-            let config = serde_yaml::from_str(&data)?;
-
-            Ok(config)
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Get repository configuration, merge it with its base one and
+        // enforce the policy
+        let config = get_config_file(&client, &ctx.owner, &ctx.repo, "").await?;
+        let config = resolve_config(&client, ctx, config).await?;
+
+        Ok(self.enforce_policy(config))
+    }
+
+    /// [GHClient::get_config_at]
+    async fn get_config_at(&self, ctx: &Ctx, sha: &str) -> Result<Option<Config>> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Get repository configuration, merge it with its base one and
+        // enforce the policy
+        let config = get_config_file(&client, &ctx.owner, &ctx.repo, sha).await?;
+        let config = resolve_config(&client, ctx, config).await?;
+
+        Ok(self.enforce_policy(config))
+    }
+
+    /// [GHClient::get_label_applier]
+    async fn get_label_applier(&self, ctx: &Ctx, issue_number: i64, label: &str) -> Result<Option<String>> {
+        // Look for the most recent labeled event for the label provided in the
+        // issue events (sorted from oldest to newest). The events are
+        // requested directly, as octorust is not able to deserialize them
+        const PER_PAGE: usize = 100;
+        const MAX_PAGES: usize = 10;
+        let path = format!("/repos/{}/{}/issues/{issue_number}/events", ctx.owner, ctx.repo);
+        let mut label_applier = None;
+        for page in 1..=MAX_PAGES {
+            let query = [("per_page", PER_PAGE.to_string()), ("page", page.to_string())];
+            let events: Vec<IssueEvent> =
+                self.raw_get(ctx.inst_id, &path, &query).await?.error_for_status()?.json().await?;
+            let page_events_count = events.len();
+            for event in events {
+                if event.event == "labeled" && event.label.is_some_and(|l| l.name == label) {
+                    label_applier = event.actor.map(|actor| actor.login);
+                }
+            }
+            if page_events_count < PER_PAGE {
+                break;
+            }
         }
 
+        Ok(label_applier)
+    }
+
+    /// [GHClient::get_pull_request]
+    async fn get_pull_request(&self, ctx: &Ctx, number: i64) -> Result<PullRequest> {
         // Setup client for installation provided
         let client = self.setup_client(ctx.inst_id)?;
 
-        inner(&client, ctx).await
+        // Get pull request
+        let pull_request = client.pulls().get(&ctx.owner, &ctx.repo, number).await?.body;
+
+        Ok(pull_request.into())
+    }
+
+    /// [GHClient::get_repository_permission]
+    async fn get_repository_permission(&self, ctx: &Ctx, username: &str) -> Result<RepositoryPermission> {
+        // Get user's permission level on the repository. It is requested
+        // directly, as octorust does not provide the role name (the legacy
+        // permission reports the maintain and triage roles as write and read)
+        let path = format!(
+            "/repos/{}/{}/collaborators/{username}/permission",
+            ctx.owner, ctx.repo
+        );
+        let resp = self.raw_get(ctx.inst_id, &path, &[]).await?;
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(RepositoryPermission::None);
+        }
+        let collaborator_permission: CollaboratorPermission = resp.error_for_status()?.json().await?;
+
+        Ok(RepositoryPermission::from(&collaborator_permission))
+    }
+
+    /// [GHClient::invalidate_config_cache]
+    async fn invalidate_config_cache(&self, owner: &str, repo: &str) {
+        GET_CONFIG_FILE.write().await.cache_remove(&format!("{owner}/{repo}@"));
+    }
+
+    /// [GHClient::invalidate_organization_member_cache]
+    async fn invalidate_organization_member_cache(&self, org: &str, login: &str) {
+        CHECK_ORGANIZATION_MEMBERSHIP.write().await.cache_remove(&format!("{org}/{login}"));
     }
 
     /// [GHClient::is_organization_member]
     async fn is_organization_member(&self, ctx: &Ctx, org: &str, username: &str) -> Result<bool> {
-        #[cached(
-            max_size = 1000,
-            ttl = 3600,
-            sync_writes = "default",
-            key = "String",
-            convert = r#"{ format!("{}-{}", org, username) }"#
-        )]
-        async fn inner(client: &octorust::Client, org: &str, username: &str) -> Result<bool> {
-            // Check if user is a member of the organization
-            let resp = match client.orgs().check_membership_for_user(org, username).await {
-                Ok(resp) => resp,
-                Err(octorust::ClientError::HttpError {
-                    status,
-                    headers: _,
-                    error,
-                }) => {
-                    if status == StatusCode::NOT_FOUND {
-                        return Ok(false);
-                    }
-                    bail!(error);
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        check_organization_membership(&client, org, username).await
+    }
+
+    /// [GHClient::is_team_member]
+    async fn is_team_member(&self, ctx: &Ctx, org: &str, team_slug: &str, username: &str) -> Result<bool> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Check if user is an active member of the team
+        let resp = match client.teams().get_membership_for_user_in_org(org, team_slug, username).await {
+            Ok(resp) => resp,
+            Err(octorust::ClientError::HttpError {
+                status,
+                headers: _,
+                error,
+            }) => {
+                if status == StatusCode::NOT_FOUND {
+                    return Ok(false);
                 }
-                Err(err) => bail!(err),
-            };
-            Ok(resp.status == StatusCode::NO_CONTENT)
+                bail!(error);
+            }
+            Err(err) => bail!(err),
+        };
+
+        Ok(resp.body.state == octorust::types::OrgMembershipState::Active)
+    }
+
+    /// [GHClient::list_commit_files]
+    async fn list_commit_files(&self, ctx: &Ctx, sha: &str) -> Result<Vec<String>> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // List files modified by the commit (paginating through all of them)
+        const PER_PAGE: i64 = 100;
+        let mut files = vec![];
+        let mut page = 1;
+        loop {
+            let commit = client.repos().get_commit(&ctx.owner, &ctx.repo, page, PER_PAGE, sha).await?.body;
+            let page_files_count = commit.files.len();
+            files.extend(commit.files.into_iter().map(|f| f.filename));
+            if page_files_count < PER_PAGE as usize {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(files)
+    }
+
+    /// [GHClient::list_commit_pull_requests]
+    async fn list_commit_pull_requests(&self, ctx: &Ctx, sha: &str) -> Result<Vec<CommitPullRequest>> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // List pull requests associated with the commit
+        let pull_requests = client
+            .repos()
+            .list_all_pull_requests_associated_with_commit(&ctx.owner, &ctx.repo, sha)
+            .await?
+            .body
+            .into_iter()
+            .map(|pr| CommitPullRequest {
+                merged_at: pr.merged_at,
+                number: pr.number,
+            })
+            .collect();
+
+        Ok(pull_requests)
+    }
+
+    /// [GHClient::list_open_pull_requests]
+    async fn list_open_pull_requests(&self, ctx: &Ctx) -> Result<Vec<PullRequest>> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // List open pull requests
+        let pull_requests = client
+            .pulls()
+            .list_all(
+                &ctx.owner,
+                &ctx.repo,
+                octorust::types::IssuesListState::Open,
+                "",
+                "",
+                octorust::types::PullsListSort::Updated,
+                octorust::types::Order::Desc,
+            )
+            .await?
+            .body
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(pull_requests)
+    }
+
+    /// [GHClient::purge_account_cache]
+    async fn purge_account_cache(&self, account: &str) {
+        let prefix = format!("{account}/");
+        GET_CONFIG_FILE.write().await.retain(|key, _| !key.starts_with(&prefix));
+        CHECK_ORGANIZATION_MEMBERSHIP.write().await.retain(|key, _| !key.starts_with(&prefix));
+    }
+
+    /// [GHClient::remove_issue_label]
+    async fn remove_issue_label(&self, ctx: &Ctx, issue_number: i64, label: &str) -> Result<()> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Remove label (the issue may not have it)
+        match client.issues().remove_label(&ctx.owner, &ctx.repo, issue_number, label).await {
+            Ok(_) => Ok(()),
+            Err(octorust::ClientError::HttpError {
+                status,
+                headers: _,
+                error,
+            }) => {
+                if status == StatusCode::NOT_FOUND {
+                    return Ok(());
+                }
+                bail!(error);
+            }
+            Err(err) => bail!(err),
         }
+    }
+
+    /// [GHClient::update_check_run]
+    async fn update_check_run(&self, ctx: &Ctx, check_run_id: i64, check_run: &CheckRun) -> Result<()> {
+        // Setup client for installation provided
+        let client = self.setup_client(ctx.inst_id)?;
+
+        // Update check run
+        let body = octorust::types::ChecksUpdateRequest {
+            actions: check_run.actions.iter().cloned().map(Into::into).collect(),
+            completed_at: check_run.completed_at,
+            conclusion: check_run.conclusion.clone().map(Into::into),
+            details_url: String::new(),
+            external_id: String::new(),
+            name: check_run.name.clone(),
+            output: Some(octorust::types::ChecksUpdateRequestOutput {
+                annotations: vec![],
+                images: vec![],
+                summary: check_run.summary.clone(),
+                text: String::new(),
+                title: check_run.title.clone(),
+            }),
+            started_at: Some(check_run.started_at),
+            status: Some(check_run.status.clone().into()),
+        };
+        client.checks().update(&ctx.owner, &ctx.repo, check_run_id, &body).await?;
 
+        Ok(())
+    }
+
+    /// [GHClient::update_issue_comment]
+    async fn update_issue_comment(&self, ctx: &Ctx, comment_id: i64, body: &str) -> Result<()> {
         // Setup client for installation provided
         let client = self.setup_client(ctx.inst_id)?;
 
-        inner(&client, org, username).await
+        // Update comment
+        let body = octorust::types::PullsUpdateReviewRequest {
+            body: body.to_string(),
+        };
+        client.issues().update_comment(&ctx.owner, &ctx.repo, comment_id, &body).await?;
+
+        Ok(())
+    }
+}
+
+/// Create an access token for the installation provided using the client
+/// provided (authenticated as the app). Tokens are valid for one hour, so they
+/// are cached for a bit less than that.
+#[cached(
+    max_size = 1000,
+    ttl = 3000,
+    sync_writes = "default",
+    key = "i64",
+    convert = r#"{ inst_id }"#
+)]
+async fn create_installation_token(client: &octorust::Client, inst_id: i64) -> Result<String> {
+    let body = octorust::types::AppsCreateInstallationAccessTokenRequest {
+        permissions: None,
+        repositories: vec![],
+        repository_ids: vec![],
+    };
+    let token = client.apps().create_installation_access_token(inst_id, &body).await?.body.token;
+
+    Ok(token)
+}
+
+/// Check if a user is a member of the organization. Memberships are cached,
+/// and invalidated when a member is added or removed from the organization.
+#[cached(
+    max_size = 1000,
+    ttl = 3600,
+    sync_writes = "default",
+    key = "String",
+    convert = r#"{ format!("{org}/{username}") }"#
+)]
+async fn check_organization_membership(client: &octorust::Client, org: &str, username: &str) -> Result<bool> {
+    // Check if user is a member of the organization
+    let resp = match client.orgs().check_membership_for_user(org, username).await {
+        Ok(resp) => resp,
+        Err(octorust::ClientError::HttpError {
+            status,
+            headers: _,
+            error,
+        }) => {
+            if status == StatusCode::NOT_FOUND {
+                return Ok(false);
+            }
+            bail!(error);
+        }
+        Err(err) => bail!(err),
+    };
+    Ok(resp.status == StatusCode::NO_CONTENT)
+}
+
+/// Merge the repository configuration provided with its base configuration.
+/// The base configuration is the one referenced in the `_extends` key or, when
+/// not set, the organization-wide one (from the organization's `.github`
+/// repository). Only one level of extension is supported, so the `_extends`
+/// key of the base configuration is ignored.
+async fn resolve_config(
+    client: &octorust::Client,
+    ctx: &Ctx,
+    config: Option<Config>,
+) -> Result<Option<Config>> {
+    let base_config = match config.as_ref().and_then(|config| config.extends.as_deref()) {
+        Some(extends) => {
+            // The configuration referenced explicitly must exist, otherwise
+            // the settings expected to be inherited would be silently ignored
+            let (owner, repo) = extends.split_once('/').unwrap_or((&ctx.owner, extends));
+            let base_config = get_config_file(client, owner, repo, "").await?;
+            if base_config.is_none() {
+                let mut err = anyhow::Error::new(ConfigError::Validation(vec![format!(
+                    "no configuration file found in repository `{owner}/{repo}` referenced in `_extends` (it may not exist or not be accessible to the app)"
+                )]));
+                if let Some(source) = config.as_ref().and_then(|config| config.sources.first()) {
+                    err = err.context(source.clone());
+                }
+                return Err(err);
+            }
+            base_config
+        }
+        None if ctx.repo != ORG_CONFIG_REPO => {
+            get_config_file(client, &ctx.owner, ORG_CONFIG_REPO, "").await?
+        }
+        None => None,
+    };
+
+    Ok(merge_section(config, base_config, Config::merge))
+}
+
+/// Merge the optional configuration section provided with its base one. When
+/// both are set, they are merged using the function provided.
+fn merge_section<T>(section: Option<T>, base: Option<T>, merge: fn(T, T) -> T) -> Option<T> {
+    match (section, base) {
+        (Some(section), Some(base)) => Some(merge(section, base)),
+        (section, base) => section.or(base),
+    }
+}
+
+/// Get the configuration file at the git reference provided and parse it. An
+/// empty reference corresponds to the repository's default branch; any other
+/// reference is expected to be a commit sha. Configurations are cached per
+/// repository and reference, so the organization-wide one is shared by all
+/// the repositories in the organization. Configurations from the default
+/// branch are invalidated when the configuration file is modified.
+#[cached(
+    max_size = 1000,
+    ttl = 3600,
+    sync_writes = "default",
+    key = "String",
+    convert = r#"{ format!("{owner}/{repo}@{git_ref}") }"#
+)]
+async fn get_config_file(
+    client: &octorust::Client,
+    owner: &str,
+    repo: &str,
+    git_ref: &str,
+) -> Result<Option<Config>> {
+    // Get configuration file content (from the first path where it exists)
+    let mut content = None;
+    for path in CONFIG_FILE_PATHS {
+        match client.repos().get_content_file(owner, repo, path, git_ref).await {
+            Ok(resp) => {
+                content = Some((path, resp.body.content));
+                break;
+            }
+            Err(octorust::ClientError::HttpError {
+                status,
+                headers: _,
+                error,
+            }) => {
+                if status == StatusCode::NOT_FOUND {
+                    continue;
+                }
+                bail!(error);
+            }
+            Err(err) => bail!(err),
+        }
     }
+    let Some((path, content)) = content else {
+        return Ok(None);
+    };
+    let source = ConfigSource {
+        owner: owner.to_string(),
+        path: path.to_string(),
+        repo: repo.to_string(),
+    };
+
+    // Decode and parse configuration (errors are tagged with the file they
+    // come from, so that it can be reported)
+    let mut b64data = content.as_bytes().to_owned();
+    b64data.retain(|b| !b" \n\t\r\x0b\x0c".contains(b));
+    let data = String::from_utf8(b64.decode(b64data)?)?;
+    let config = Config::from_yaml(&data).context(source.clone())?.map(|mut config| {
+        config.sources = vec![source];
+        config
+    });
+
+    Ok(config)
 }
 
 /// GitHub application configuration.
@@ -216,8 +899,8 @@ pub struct AppConfig {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CheckRun {
     actions: Vec<CheckRunAction>,
-    completed_at: DateTime<Utc>,
-    conclusion: CheckRunConclusion,
+    completed_at: Option<DateTime<Utc>>,
+    conclusion: Option<CheckRunConclusion>,
     head_sha: String,
     name: String,
     started_at: DateTime<Utc>,
@@ -284,14 +967,14 @@ impl CheckRun {
         &self.actions
     }
 
-    /// Get the completion time of the check run.
-    pub fn completed_at(&self) -> &DateTime<Utc> {
-        &self.completed_at
+    /// Get the completion time of the check run (if completed).
+    pub fn completed_at(&self) -> Option<&DateTime<Utc>> {
+        self.completed_at.as_ref()
     }
 
-    /// Get the conclusion of the check run.
-    pub fn conclusion(&self) -> &CheckRunConclusion {
-        &self.conclusion
+    /// Get the conclusion of the check run (if completed).
+    pub fn conclusion(&self) -> Option<&CheckRunConclusion> {
+        self.conclusion.as_ref()
     }
 
     /// Get the head SHA of the check run.
@@ -350,6 +1033,8 @@ impl From<CheckRunAction> for octorust::types::ChecksCreateRequestActions {
 pub enum CheckRunConclusion {
     Success,
     ActionRequired,
+    Failure,
+    Neutral,
 }
 
 impl From<CheckRunConclusion> for octorust::types::ChecksCreateRequestConclusion {
@@ -360,6 +1045,8 @@ impl From<CheckRunConclusion> for octorust::types::ChecksCreateRequestConclusion
             CheckRunConclusion::ActionRequired => {
                 octorust::types::ChecksCreateRequestConclusion::ActionRequired
             }
+            CheckRunConclusion::Failure => octorust::types::ChecksCreateRequestConclusion::Failure,
+            CheckRunConclusion::Neutral => octorust::types::ChecksCreateRequestConclusion::Neutral,
         }
     }
 }
@@ -368,6 +1055,8 @@ impl From<CheckRunConclusion> for octorust::types::ChecksCreateRequestConclusion
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum CheckRunStatus {
+    Queued,
+    InProgress,
     Completed,
 }
 
@@ -375,6 +1064,8 @@ impl From<CheckRunStatus> for octorust::types::JobStatus {
     /// Convert CheckRunStatus to octorust JobStatus.
     fn from(s: CheckRunStatus) -> octorust::types::JobStatus {
         match s {
+            CheckRunStatus::Queued => octorust::types::JobStatus::Queued,
+            CheckRunStatus::InProgress => octorust::types::JobStatus::InProgress,
             CheckRunStatus::Completed => octorust::types::JobStatus::Completed,
         }
     }
@@ -388,11 +1079,25 @@ pub struct Ctx {
     pub repo: String,
 }
 
+/// Result of comparing two commits.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Comparison {
+    /// Commits between the base and the head commits.
+    pub commits: Vec<Commit>,
+
+    /// Files modified between the base and the head commits. GitHub includes
+    /// up to 300 files in the comparison.
+    pub files: Vec<String>,
+}
+
 /// Commit information.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Commit {
     pub author: Option<User>,
+    pub author_date: Option<DateTime<Utc>>,
     pub committer: Option<User>,
+    pub committer_date: Option<DateTime<Utc>>,
+    pub files: Option<Vec<String>>,
     pub html_url: String,
     pub is_merge: bool,
     pub message: String,
@@ -403,6 +1108,11 @@ pub struct Commit {
 impl From<octorust::types::CommitDataType> for Commit {
     /// Convert octorust commit data to Commit.
     fn from(c: octorust::types::CommitDataType) -> Self {
+        // Parse author and committer dates
+        let parse_date = |date: &str| DateTime::parse_from_rfc3339(date).ok().map(|d| d.with_timezone(&Utc));
+        let author_date = c.commit.author.as_ref().and_then(|author| parse_date(&author.date));
+        let committer_date = c.commit.committer.as_ref().and_then(|committer| parse_date(&committer.date));
+
         Self {
             author: c.commit.author.map(|author| User {
                 name: author.name,
@@ -410,12 +1120,15 @@ impl From<octorust::types::CommitDataType> for Commit {
                 is_bot: c.author.as_ref().is_some_and(|a| a.type_ == "Bot"),
                 login: c.author.map(|a| a.login),
             }),
+            author_date,
             committer: c.commit.committer.map(|committer| User {
                 name: committer.name,
                 email: committer.email,
                 is_bot: c.committer.as_ref().is_some_and(|c| c.type_ == "Bot"),
                 login: c.committer.map(|c| c.login),
             }),
+            committer_date,
+            files: None,
             html_url: c.html_url,
             is_merge: c.parents.len() > 1,
             message: c.commit.message,
@@ -425,32 +1138,456 @@ impl From<octorust::types::CommitDataType> for Commit {
     }
 }
 
-/// Default values for the configuration.
-pub const DEFAULT_OVERRIDE_ACTION_ALLOWED: bool = true;
-pub const DEFAULT_MEMBERS_SIGNOFF_REQUIRED: bool = true;
-pub const DEFAULT_INDIVIDUAL_REMEDIATION_COMMITS_ALLOWED: bool = false;
-pub const DEFAULT_THIRD_PARTY_REMEDIATION_COMMITS_ALLOWED: bool = false;
+/// Pull request associated with a commit.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommitPullRequest {
+    pub merged_at: Option<DateTime<Utc>>,
+    pub number: i64,
+}
 
-/// Repository configuration.
+/// Commit status.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
-pub struct Config {
-    pub allow_override_action: Option<bool>,
-    pub allow_remediation_commits: Option<ConfigAllowRemediationCommits>,
+pub struct CommitStatus {
+    context: String,
+    description: String,
+    sha: String,
+    state: CommitStatusState,
+}
+
+impl CommitStatus {
+    /// Create a new CommitStatus instance.
+    pub fn new(input: NewCommitStatusInput) -> Self {
+        // Create a new commit status from the input received.
+        let mut commit_status = Self {
+            context: input.context,
+            description: input.description,
+            sha: input.sha,
+            state: input.state,
+        };
+
+        // Make sure the description is below the maximum length allowed by
+        // GitHub (we'll truncate it if necessary).
+        const MAX_DESCRIPTION_LENGTH: usize = 140;
+        if commit_status.description.chars().count() > MAX_DESCRIPTION_LENGTH {
+            commit_status.description =
+                commit_status.description.chars().take(MAX_DESCRIPTION_LENGTH).collect();
+            warn!("commit status description truncated");
+        }
+
+        commit_status
+    }
+
+    /// Get the context of the commit status.
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+
+    /// Get the description of the commit status.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Get the SHA of the commit the status applies to.
+    pub fn sha(&self) -> &str {
+        &self.sha
+    }
+
+    /// Get the state of the commit status.
+    pub fn state(&self) -> &CommitStatusState {
+        &self.state
+    }
+}
+
+/// Commit status state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitStatusState {
+    Pending,
+    Success,
+    Failure,
+}
+
+impl From<&CheckRunConclusion> for CommitStatusState {
+    /// Convert CheckRunConclusion to CommitStatusState. Commit statuses do not
+    /// support a neutral state, so neutral conclusions (which do not block
+    /// pull requests) are reported as success.
+    fn from(c: &CheckRunConclusion) -> Self {
+        match c {
+            CheckRunConclusion::Success | CheckRunConclusion::Neutral => CommitStatusState::Success,
+            CheckRunConclusion::ActionRequired | CheckRunConclusion::Failure => CommitStatusState::Failure,
+        }
+    }
+}
+
+impl From<CommitStatusState> for octorust::types::ReposCreateCommitStatusRequestState {
+    /// Convert CommitStatusState to octorust ReposCreateCommitStatusRequestState.
+    fn from(s: CommitStatusState) -> octorust::types::ReposCreateCommitStatusRequestState {
+        match s {
+            CommitStatusState::Pending => octorust::types::ReposCreateCommitStatusRequestState::Pending,
+            CommitStatusState::Success => octorust::types::ReposCreateCommitStatusRequestState::Success,
+            CommitStatusState::Failure => octorust::types::ReposCreateCommitStatusRequestState::Failure,
+        }
+    }
+}
+
+/// Default values for the configuration.
+pub const DEFAULT_OVERRIDE_ACTION_ALLOWED: bool = true;
+pub const DEFAULT_OVERRIDE_JUSTIFICATION_REQUIRED: bool = false;
+pub const DEFAULT_CHERRY_PICKS_ALLOWED: bool = false;
+pub const DEFAULT_MEMBERS_SIGNOFF_REQUIRED: bool = true;
+pub const DEFAULT_INDIVIDUAL_REMEDIATION_COMMITS_ALLOWED: bool = false;
+pub const DEFAULT_THIRD_PARTY_REMEDIATION_COMMITS_ALLOWED: bool = false;
+pub const DEFAULT_SQUASH_MERGE_ENABLED: bool = false;
+pub const DEFAULT_DRAFTS_DEFERRED: bool = false;
+pub const DEFAULT_FAILURE_COMMENT_ENABLED: bool = false;
+pub const DEFAULT_MERGE_GROUP_CHECK_ENABLED: bool = false;
+pub const DEFAULT_BASE_BRANCH_CONFIG_USED: bool = false;
+pub const DEFAULT_PUSH_ISSUE_ENABLED: bool = false;
+pub const DEFAULT_MODE: ConfigMode = ConfigMode::Enforce;
+pub const DEFAULT_REPORT_AS: ConfigReportAs = ConfigReportAs::CheckRun;
+pub const DEFAULT_ENFORCE_AFTER_DATE_SOURCE: ConfigCommitDate = ConfigCommitDate::Author;
+
+/// Repository configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct Config {
+    pub allow_cherry_picks: Option<bool>,
+    pub allow_override_action: Option<bool>,
+    pub allow_remediation_commits: Option<ConfigAllowRemediationCommits>,
+    pub branches: Option<ConfigBranches>,
+    pub check_merge_group: Option<bool>,
+    pub check_pushes: Option<ConfigCheckPushes>,
+    pub comment_on_failure: Option<bool>,
+    pub defer_drafts: Option<bool>,
+    pub enforce_after: Option<NaiveDate>,
+    pub enforce_after_date_source: Option<ConfigCommitDate>,
+    pub exempt_paths: Option<Vec<String>>,
+    pub exemption_label: Option<String>,
+    #[serde(rename = "_extends")]
+    pub extends: Option<String>,
+    pub failure_label: Option<String>,
+    pub mode: Option<ConfigMode>,
+    pub override_allowed_by: Option<ConfigOverrideAllowedBy>,
+    pub report_as: Option<ConfigReportAs>,
     pub require: Option<ConfigRequire>,
+    pub require_override_justification: Option<bool>,
+    pub squash_merge: Option<bool>,
+    pub use_base_branch_config: Option<bool>,
+
+    /// Keys found in the configuration file that are not supported (they
+    /// are ignored, but reported as warnings in the check output).
+    #[serde(skip)]
+    pub unknown_keys: Vec<String>,
+
+    /// Keys set in the configuration file whose values have been overridden
+    /// by the policy (they are reported in the check output).
+    #[serde(skip)]
+    pub policy_overrides: Vec<String>,
+
+    /// Configuration files this configuration was loaded from (the main one
+    /// first, followed by the one it extends, if any).
+    #[serde(skip)]
+    pub sources: Vec<ConfigSource>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            allow_cherry_picks: Some(DEFAULT_CHERRY_PICKS_ALLOWED),
             allow_override_action: Some(DEFAULT_OVERRIDE_ACTION_ALLOWED),
             allow_remediation_commits: Some(ConfigAllowRemediationCommits::default()),
+            branches: Some(ConfigBranches::default()),
+            check_merge_group: Some(DEFAULT_MERGE_GROUP_CHECK_ENABLED),
+            check_pushes: Some(ConfigCheckPushes::default()),
+            comment_on_failure: Some(DEFAULT_FAILURE_COMMENT_ENABLED),
+            defer_drafts: Some(DEFAULT_DRAFTS_DEFERRED),
+            enforce_after: None,
+            enforce_after_date_source: Some(DEFAULT_ENFORCE_AFTER_DATE_SOURCE),
+            exempt_paths: None,
+            exemption_label: None,
+            extends: None,
+            failure_label: None,
+            mode: Some(DEFAULT_MODE),
+            override_allowed_by: None,
+            report_as: Some(DEFAULT_REPORT_AS),
             require: Some(ConfigRequire::default()),
+            require_override_justification: Some(DEFAULT_OVERRIDE_JUSTIFICATION_REQUIRED),
+            squash_merge: Some(DEFAULT_SQUASH_MERGE_ENABLED),
+            use_base_branch_config: Some(DEFAULT_BASE_BRANCH_CONFIG_USED),
+            unknown_keys: vec![],
+            policy_overrides: vec![],
+            sources: vec![],
         }
     }
 }
 
 impl Config {
+    /// Parse the configuration from the YAML document provided, collecting
+    /// the unknown keys found and validating the values that are not checked
+    /// when deserializing. An empty document yields no configuration.
+    pub fn from_yaml(data: &str) -> Result<Option<Self>, ConfigError> {
+        // Parse configuration, keeping track of the keys ignored
+        let mut unknown_keys = vec![];
+        let deserializer = serde_yaml::Deserializer::from_str(data);
+        let config: Option<Config> = serde_ignored::deserialize(deserializer, |path| {
+            // Optional values are displayed as `?` in the path, so we skip them
+            let key = path
+                .to_string()
+                .split('.')
+                .filter(|segment| *segment != "?")
+                .collect::<Vec<_>>()
+                .join(".");
+            unknown_keys.push(key);
+        })
+        .map_err(|err| ConfigError::from(&err))?;
+        let Some(mut config) = config else {
+            return Ok(None);
+        };
+        config.unknown_keys = unknown_keys;
+
+        // Validate glob patterns
+        let patterns = [
+            ("exemptPaths", config.exempt_paths.as_ref()),
+            (
+                "branches.include",
+                config.branches.as_ref().and_then(|b| b.include.as_ref()),
+            ),
+            (
+                "branches.exclude",
+                config.branches.as_ref().and_then(|b| b.exclude.as_ref()),
+            ),
+            (
+                "checkPushes.branches",
+                config.check_pushes.as_ref().and_then(|c| c.branches.as_ref()),
+            ),
+        ];
+        let mut errors = vec![];
+        for (key, patterns) in patterns {
+            for pattern in patterns.into_iter().flatten() {
+                if let Err(err) = Glob::new(pattern) {
+                    errors.push(format!("invalid pattern `{pattern}` in `{key}`: {}", err.kind()));
+                }
+            }
+        }
+
+        // Validate extended repository reference
+        if let Some(extends) = &config.extends {
+            let valid = match extends.split_once('/') {
+                Some((owner, repo)) => !owner.is_empty() && !repo.is_empty() && !repo.contains('/'),
+                None => !extends.is_empty(),
+            };
+            if !valid {
+                errors.push(format!(
+                    "invalid repository `{extends}` in `_extends`: expected `repo` or `owner/repo`"
+                ));
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ConfigError::Validation(errors));
+        }
+
+        Ok(Some(config))
+    }
+
+    /// Merge this configuration with the base configuration provided. Keys
+    /// set in this configuration take precedence over the base ones. Sections
+    /// set in both configurations are merged key by key.
+    #[must_use]
+    pub fn merge(self, base: Config) -> Config {
+        Config {
+            allow_cherry_picks: self.allow_cherry_picks.or(base.allow_cherry_picks),
+            allow_override_action: self.allow_override_action.or(base.allow_override_action),
+            allow_remediation_commits: merge_section(
+                self.allow_remediation_commits,
+                base.allow_remediation_commits,
+                ConfigAllowRemediationCommits::merge,
+            ),
+            branches: merge_section(self.branches, base.branches, ConfigBranches::merge),
+            check_merge_group: self.check_merge_group.or(base.check_merge_group),
+            check_pushes: merge_section(self.check_pushes, base.check_pushes, ConfigCheckPushes::merge),
+            comment_on_failure: self.comment_on_failure.or(base.comment_on_failure),
+            defer_drafts: self.defer_drafts.or(base.defer_drafts),
+            enforce_after: self.enforce_after.or(base.enforce_after),
+            enforce_after_date_source: self.enforce_after_date_source.or(base.enforce_after_date_source),
+            exempt_paths: self.exempt_paths.or(base.exempt_paths),
+            exemption_label: self.exemption_label.or(base.exemption_label),
+            extends: self.extends,
+            failure_label: self.failure_label.or(base.failure_label),
+            mode: self.mode.or(base.mode),
+            override_allowed_by: merge_section(
+                self.override_allowed_by,
+                base.override_allowed_by,
+                ConfigOverrideAllowedBy::merge,
+            ),
+            report_as: self.report_as.or(base.report_as),
+            require: merge_section(self.require, base.require, ConfigRequire::merge),
+            require_override_justification: self
+                .require_override_justification
+                .or(base.require_override_justification),
+            squash_merge: self.squash_merge.or(base.squash_merge),
+            use_base_branch_config: self.use_base_branch_config.or(base.use_base_branch_config),
+            unknown_keys: self.unknown_keys,
+            policy_overrides: self.policy_overrides,
+            sources: self.sources.into_iter().chain(base.sources).collect(),
+        }
+    }
+
+    /// Apply the policy provided to this configuration, replacing the values
+    /// of the settings locked by it. The keys set in this configuration with
+    /// a different value are tracked as overridden.
+    pub fn apply_policy(&mut self, policy: &ConfigPolicy) {
+        /// Set the value provided, tracking if the setting was overridden.
+        fn enforce<T: Clone + PartialEq>(
+            overrides: &mut Vec<String>,
+            key: &str,
+            setting: &mut Option<T>,
+            value: Option<&T>,
+        ) {
+            let Some(value) = value else {
+                return;
+            };
+            if setting.as_ref().is_some_and(|setting| setting != value) {
+                overrides.push(key.to_string());
+            }
+            *setting = Some(value.clone());
+        }
+
+        /// Set the value provided or, when the setting is locked and no value
+        /// has been provided, unset it, tracking if the setting was overridden.
+        fn lock<T: Clone + PartialEq>(
+            overrides: &mut Vec<String>,
+            key: &str,
+            setting: &mut Option<T>,
+            value: Option<&T>,
+            locked: bool,
+        ) {
+            if value.is_some() || !locked {
+                return enforce(overrides, key, setting, value);
+            }
+            if setting.take().is_some() {
+                overrides.push(key.to_string());
+            }
+        }
+
+        let mut overrides = vec![];
+        enforce(
+            &mut overrides,
+            "allowCherryPicks",
+            &mut self.allow_cherry_picks,
+            policy.allow_cherry_picks.as_ref(),
+        );
+        enforce(
+            &mut overrides,
+            "allowOverrideAction",
+            &mut self.allow_override_action,
+            policy.allow_override_action.as_ref(),
+        );
+        if policy.allow_individual_remediation_commits.is_some()
+            || policy.allow_third_party_remediation_commits.is_some()
+        {
+            let allow_remediation_commits =
+                self.allow_remediation_commits.get_or_insert(ConfigAllowRemediationCommits {
+                    individual: None,
+                    third_party: None,
+                });
+            enforce(
+                &mut overrides,
+                "allowRemediationCommits.individual",
+                &mut allow_remediation_commits.individual,
+                policy.allow_individual_remediation_commits.as_ref(),
+            );
+            enforce(
+                &mut overrides,
+                "allowRemediationCommits.thirdParty",
+                &mut allow_remediation_commits.third_party,
+                policy.allow_third_party_remediation_commits.as_ref(),
+            );
+        }
+        if policy.branches_locked || policy.branches_exclude.is_some() || policy.branches_include.is_some() {
+            let branches = self.branches.get_or_insert(ConfigBranches {
+                include: None,
+                exclude: None,
+            });
+            lock(
+                &mut overrides,
+                "branches.exclude",
+                &mut branches.exclude,
+                policy.branches_exclude.as_ref(),
+                policy.branches_locked,
+            );
+            lock(
+                &mut overrides,
+                "branches.include",
+                &mut branches.include,
+                policy.branches_include.as_ref(),
+                policy.branches_locked,
+            );
+        }
+        enforce(
+            &mut overrides,
+            "deferDrafts",
+            &mut self.defer_drafts,
+            policy.defer_drafts.as_ref(),
+        );
+        lock(
+            &mut overrides,
+            "enforceAfter",
+            &mut self.enforce_after,
+            policy.enforce_after.as_ref(),
+            policy.enforce_after_locked,
+        );
+        lock(
+            &mut overrides,
+            "exemptPaths",
+            &mut self.exempt_paths,
+            policy.exempt_paths.as_ref(),
+            policy.exempt_paths_locked,
+        );
+        lock(
+            &mut overrides,
+            "exemptionLabel",
+            &mut self.exemption_label,
+            policy.exemption_label.as_ref(),
+            policy.exemption_label_locked,
+        );
+        enforce(&mut overrides, "mode", &mut self.mode, policy.mode.as_ref());
+        enforce(
+            &mut overrides,
+            "reportAs",
+            &mut self.report_as,
+            policy.report_as.as_ref(),
+        );
+        if policy.require_members.is_some() {
+            let require = self.require.get_or_insert(ConfigRequire { members: None });
+            enforce(
+                &mut overrides,
+                "require.members",
+                &mut require.members,
+                policy.require_members.as_ref(),
+            );
+        }
+        enforce(
+            &mut overrides,
+            "requireOverrideJustification",
+            &mut self.require_override_justification,
+            policy.require_override_justification.as_ref(),
+        );
+        enforce(
+            &mut overrides,
+            "squashMerge",
+            &mut self.squash_merge,
+            policy.squash_merge.as_ref(),
+        );
+        self.policy_overrides = overrides;
+    }
+
+    /// Check if the configuration should be read from the base branch of the
+    /// pull request (instead of from the default branch).
+    pub fn base_branch_config_is_used(&self) -> bool {
+        self.use_base_branch_config.unwrap_or(DEFAULT_BASE_BRANCH_CONFIG_USED)
+    }
+
     /// Check if individual remediation commits are allowed.
     pub fn individual_remediation_commits_are_allowed(&self) -> bool {
         if let Some(allow_remediation_commits) = &self.allow_remediation_commits {
@@ -473,11 +1610,176 @@ impl Config {
         }
     }
 
+    /// Check if cherry-picked commits can rely on the sign-off of the original
+    /// commit they reference.
+    pub fn cherry_picks_are_allowed(&self) -> bool {
+        self.allow_cherry_picks.unwrap_or(DEFAULT_CHERRY_PICKS_ALLOWED)
+    }
+
     /// Check if the override action is allowed.
     pub fn override_action_is_allowed(&self) -> bool {
         self.allow_override_action.unwrap_or(DEFAULT_OVERRIDE_ACTION_ALLOWED)
     }
 
+    /// Check if the check must be deferred on draft pull requests until they
+    /// are marked as ready for review.
+    pub fn drafts_are_deferred(&self) -> bool {
+        self.defer_drafts.unwrap_or(DEFAULT_DRAFTS_DEFERRED)
+    }
+
+    /// Check if a comment summarizing the failure must be posted on pull
+    /// requests that do not pass the check.
+    pub fn failure_comment_is_enabled(&self) -> bool {
+        self.comment_on_failure.unwrap_or(DEFAULT_FAILURE_COMMENT_ENABLED)
+    }
+
+    /// Check if overrides require a justification. When required, the
+    /// override button is not displayed and the check can only be overridden
+    /// using the override command (which includes a reason).
+    pub fn override_justification_is_required(&self) -> bool {
+        self.require_override_justification.unwrap_or(DEFAULT_OVERRIDE_JUSTIFICATION_REQUIRED)
+    }
+
+    /// Check if the override action is restricted to some users, teams or
+    /// to users with a minimum permission on the repository.
+    pub fn override_action_is_restricted(&self) -> bool {
+        self.override_allowed_by.as_ref().is_some_and(|allowed_by| {
+            allowed_by.users.as_ref().is_some_and(|users| !users.is_empty())
+                || allowed_by.teams.as_ref().is_some_and(|teams| !teams.is_empty())
+                || allowed_by.minimum_permission.is_some()
+        })
+    }
+
+    /// Check if the DCO check is enforced on pull requests targeting the
+    /// base branch provided.
+    pub fn branch_is_enforced(&self, branch: &str) -> bool {
+        let Some(branches) = &self.branches else {
+            return true;
+        };
+
+        // When a list of branches to include is provided, the branch must
+        // match at least one of the patterns
+        if let Some(include) = &branches.include
+            && !include.is_empty()
+            && !include.iter().any(|pattern| glob_matches(pattern, branch))
+        {
+            return false;
+        }
+
+        // The branch must not match any of the patterns to exclude
+        if let Some(exclude) = &branches.exclude
+            && exclude.iter().any(|pattern| glob_matches(pattern, branch))
+        {
+            return false;
+        }
+
+        true
+    }
+
+    /// Check if the commit provided predates the enforcement date (if any).
+    /// Commits without the date used for the comparison are never considered
+    /// older than the enforcement date.
+    pub fn commit_predates_enforcement(&self, commit: &Commit) -> bool {
+        let Some(enforce_after) = self.enforce_after else {
+            return false;
+        };
+        let commit_date =
+            match self.enforce_after_date_source.as_ref().unwrap_or(&DEFAULT_ENFORCE_AFTER_DATE_SOURCE) {
+                ConfigCommitDate::Author => commit.author_date,
+                ConfigCommitDate::Committer => commit.committer_date,
+            };
+        commit_date.is_some_and(|commit_date| commit_date.date_naive() < enforce_after)
+    }
+
+    /// Check if the configuration contains any exempt paths.
+    pub fn exempt_paths_are_configured(&self) -> bool {
+        self.exempt_paths.as_ref().is_some_and(|patterns| !patterns.is_empty())
+    }
+
+    /// Get the exempt paths patterns matched by the files modified by the
+    /// commit provided, if all of them are exempt. When any of the files is
+    /// not exempt (or the commit files are not available), None is returned.
+    pub fn exempt_paths_matched(&self, commit: &Commit) -> Option<Vec<String>> {
+        let (Some(patterns), Some(files)) = (&self.exempt_paths, &commit.files) else {
+            return None;
+        };
+        if files.is_empty() {
+            return None;
+        }
+
+        let mut matched_patterns: Vec<String> = vec![];
+        for file in files {
+            let file_patterns: Vec<&String> =
+                patterns.iter().filter(|pattern| glob_matches(pattern, file)).collect();
+            if file_patterns.is_empty() {
+                return None;
+            }
+            for pattern in file_patterns {
+                if !matched_patterns.contains(pattern) {
+                    matched_patterns.push(pattern.clone());
+                }
+            }
+        }
+
+        Some(matched_patterns)
+    }
+
+    /// Get the label that exempts pull requests from the check (if any).
+    pub fn exemption_label(&self) -> Option<&str> {
+        self.exemption_label.as_deref().filter(|label| !label.trim().is_empty())
+    }
+
+    /// Get the label added to pull requests that fail the check (if any).
+    pub fn failure_label(&self) -> Option<&str> {
+        self.failure_label.as_deref().filter(|label| !label.trim().is_empty())
+    }
+
+    /// Check if the commits in merge groups must be checked (instead of
+    /// setting the check result to passed directly).
+    pub fn merge_group_check_is_enabled(&self) -> bool {
+        self.check_merge_group.unwrap_or(DEFAULT_MERGE_GROUP_CHECK_ENABLED)
+    }
+
+    /// Check if the commits pushed directly to the branch provided must be
+    /// checked. Direct pushes are not checked on any branch by default.
+    pub fn push_check_is_enabled(&self, branch: &str) -> bool {
+        self.check_pushes
+            .as_ref()
+            .and_then(|c| c.branches.as_ref())
+            .is_some_and(|branches| branches.iter().any(|pattern| glob_matches(pattern, branch)))
+    }
+
+    /// Check if an issue must be opened when commits pushed directly to a
+    /// branch do not pass the check.
+    pub fn push_issue_is_enabled(&self) -> bool {
+        self.check_pushes
+            .as_ref()
+            .and_then(|c| c.open_issue)
+            .unwrap_or(DEFAULT_PUSH_ISSUE_ENABLED)
+    }
+
+    /// Check if the advisory mode is enabled. In this mode, failed checks
+    /// are reported but they do not block pull requests.
+    pub fn advisory_mode_is_enabled(&self) -> bool {
+        self.mode.as_ref().unwrap_or(&DEFAULT_MODE) == &ConfigMode::Advisory
+    }
+
+    /// Check if the check result must be reported as a check run.
+    pub fn check_run_is_reported(&self) -> bool {
+        matches!(
+            self.report_as.as_ref().unwrap_or(&DEFAULT_REPORT_AS),
+            ConfigReportAs::CheckRun | ConfigReportAs::Both
+        )
+    }
+
+    /// Check if the check result must be reported as a commit status.
+    pub fn commit_status_is_reported(&self) -> bool {
+        matches!(
+            self.report_as.as_ref().unwrap_or(&DEFAULT_REPORT_AS),
+            ConfigReportAs::CommitStatus | ConfigReportAs::Both
+        )
+    }
+
     /// Check if the configuration requires members to sign-off commits.
     pub fn members_signoff_is_required(&self) -> bool {
         if let Some(require) = &self.require {
@@ -486,6 +1788,56 @@ impl Config {
             DEFAULT_MEMBERS_SIGNOFF_REQUIRED
         }
     }
+
+    /// Check if the squash merge mode is enabled. When enabled, sign-offs in
+    /// the pull request description cover the resulting squashed commit.
+    pub fn squash_merge_is_enabled(&self) -> bool {
+        self.squash_merge.unwrap_or(DEFAULT_SQUASH_MERGE_ENABLED)
+    }
+}
+
+/// Errors that may occur while loading the configuration.
+#[derive(Error, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConfigError {
+    /// The configuration file could not be parsed.
+    #[error("{}", format_parse_error(message, *line, *column))]
+    Parse {
+        message: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+
+    /// The configuration file was parsed, but some values are not valid.
+    #[error("{}", .0.join(", "))]
+    Validation(Vec<String>),
+}
+
+impl From<&serde_yaml::Error> for ConfigError {
+    /// Convert a serde_yaml error to a ConfigError.
+    fn from(err: &serde_yaml::Error) -> Self {
+        // The location is reported separately, so we remove it from the
+        // message when present
+        let location = err.location();
+        let mut message = err.to_string();
+        if let Some(location) = &location {
+            let suffix = format!(" at line {} column {}", location.line(), location.column());
+            message = message.replacen(&suffix, "", 1);
+        }
+
+        ConfigError::Parse {
+            message,
+            line: location.as_ref().map(serde_yaml::Location::line),
+            column: location.as_ref().map(serde_yaml::Location::column),
+        }
+    }
+}
+
+/// Format a parse error, including its location when available.
+fn format_parse_error(message: &str, line: Option<usize>, column: Option<usize>) -> String {
+    match (line, column) {
+        (Some(line), Some(column)) => format!("line {line}, column {column}: {message}"),
+        _ => message.to_string(),
+    }
 }
 
 /// Allow remediation commits section of the configuration.
@@ -501,6 +1853,17 @@ pub struct ConfigAllowRemediationCommits {
     pub third_party: Option<bool>,
 }
 
+impl ConfigAllowRemediationCommits {
+    /// Merge this section with the base one provided.
+    #[must_use]
+    pub fn merge(self, base: Self) -> Self {
+        Self {
+            individual: self.individual.or(base.individual),
+            third_party: self.third_party.or(base.third_party),
+        }
+    }
+}
+
 impl Default for ConfigAllowRemediationCommits {
     fn default() -> Self {
         Self {
@@ -510,6 +1873,210 @@ impl Default for ConfigAllowRemediationCommits {
     }
 }
 
+/// Branches section of the configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct ConfigBranches {
+    /// Glob patterns of the base branches on which the check is enforced.
+    /// (default: all branches)
+    pub include: Option<Vec<String>>,
+
+    /// Glob patterns of the base branches on which the check is not enforced.
+    /// (default: none)
+    pub exclude: Option<Vec<String>>,
+}
+
+impl ConfigBranches {
+    /// Merge this section with the base one provided.
+    #[must_use]
+    pub fn merge(self, base: Self) -> Self {
+        Self {
+            include: self.include.or(base.include),
+            exclude: self.exclude.or(base.exclude),
+        }
+    }
+}
+
+/// Direct pushes section of the configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct ConfigCheckPushes {
+    /// Glob patterns of the branches on which direct pushes are checked.
+    /// (default: none)
+    pub branches: Option<Vec<String>>,
+
+    /// Open an issue when the commits pushed do not pass the check.
+    /// (default: false)
+    pub open_issue: Option<bool>,
+}
+
+impl ConfigCheckPushes {
+    /// Merge this section with the base one provided.
+    #[must_use]
+    pub fn merge(self, base: Self) -> Self {
+        Self {
+            branches: self.branches.or(base.branches),
+            open_issue: self.open_issue.or(base.open_issue),
+        }
+    }
+}
+
+/// Policy enforced by the operator of the DCO2 instance over all the
+/// repositories configurations. Settings set in the policy cannot be changed
+/// by the repositories. Settings that are disabled when unset can be locked
+/// without a value using the corresponding `*_locked` flag.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct ConfigPolicy {
+    /// Accept cherry-picked commits (`allowCherryPicks`).
+    pub allow_cherry_picks: Option<bool>,
+
+    /// Allow individual remediation commits
+    /// (`allowRemediationCommits.individual`).
+    pub allow_individual_remediation_commits: Option<bool>,
+
+    /// Show the override action in failed checks (`allowOverrideAction`).
+    pub allow_override_action: Option<bool>,
+
+    /// Allow third-party remediation commits
+    /// (`allowRemediationCommits.thirdParty`).
+    pub allow_third_party_remediation_commits: Option<bool>,
+
+    /// Glob patterns of the base branches on which the check is not enforced
+    /// (`branches.exclude`).
+    pub branches_exclude: Option<Vec<String>>,
+
+    /// Glob patterns of the base branches on which the check is enforced
+    /// (`branches.include`).
+    pub branches_include: Option<Vec<String>>,
+
+    /// Prevent repositories from setting the branches on which the check is
+    /// enforced or not (`branches`), so that it is enforced on all of them
+    /// unless the patterns are set in the policy.
+    pub branches_locked: bool,
+
+    /// Defer the check on draft pull requests (`deferDrafts`).
+    pub defer_drafts: Option<bool>,
+
+    /// Date from which the check is enforced (`enforceAfter`).
+    pub enforce_after: Option<NaiveDate>,
+
+    /// Prevent repositories from setting the date from which the check is
+    /// enforced (`enforceAfter`).
+    pub enforce_after_locked: bool,
+
+    /// Glob patterns of the paths exempted from the check (`exemptPaths`).
+    pub exempt_paths: Option<Vec<String>>,
+
+    /// Prevent repositories from exempting paths from the check
+    /// (`exemptPaths`).
+    pub exempt_paths_locked: bool,
+
+    /// Label that exempts a pull request from the check (`exemptionLabel`).
+    pub exemption_label: Option<String>,
+
+    /// Prevent repositories from setting a label that exempts pull requests
+    /// from the check (`exemptionLabel`).
+    pub exemption_label_locked: bool,
+
+    /// Mode in which the check runs (`mode`).
+    pub mode: Option<ConfigMode>,
+
+    /// How the check result is reported (`reportAs`).
+    pub report_as: Option<ConfigReportAs>,
+
+    /// Require members to sign-off commits (`require.members`).
+    pub require_members: Option<bool>,
+
+    /// Require a justification to override the check
+    /// (`requireOverrideJustification`).
+    pub require_override_justification: Option<bool>,
+
+    /// Accept sign-offs in the pull request description (`squashMerge`).
+    pub squash_merge: Option<bool>,
+}
+
+/// Location of a configuration file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigSource {
+    pub owner: String,
+    pub path: String,
+    pub repo: String,
+}
+
+impl Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` in `{}/{}`", self.path, self.owner, self.repo)
+    }
+}
+
+/// Commit date used to compare commits against the enforcement date.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigCommitDate {
+    /// Date when the commit was originally authored.
+    Author,
+
+    /// Date when the commit was last committed (i.e. rebased or amended).
+    Committer,
+}
+
+/// Mode in which the DCO check runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigMode {
+    /// Failed checks block pull requests (when the check is required).
+    Enforce,
+
+    /// Failed checks are reported with a neutral conclusion.
+    Advisory,
+}
+
+/// How the check result is reported.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigReportAs {
+    /// The result is reported as a check run.
+    CheckRun,
+
+    /// The result is reported as a commit status (for legacy branch
+    /// protection rules relying on commit statuses).
+    CommitStatus,
+
+    /// The result is reported both as a check run and as a commit status.
+    Both,
+}
+
+/// Override allowed by section of the configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct ConfigOverrideAllowedBy {
+    /// Logins of the users allowed to override the check.
+    /// (default: none)
+    pub users: Option<Vec<String>>,
+
+    /// Teams whose members are allowed to override the check (`team-slug`
+    /// or `org/team-slug`).
+    /// (default: none)
+    pub teams: Option<Vec<String>>,
+
+    /// Minimum permission on the repository required to override the check.
+    /// (default: none)
+    pub minimum_permission: Option<RepositoryPermission>,
+}
+
+impl ConfigOverrideAllowedBy {
+    /// Merge this section with the base one provided.
+    #[must_use]
+    pub fn merge(self, base: Self) -> Self {
+        Self {
+            users: self.users.or(base.users),
+            teams: self.teams.or(base.teams),
+            minimum_permission: self.minimum_permission.or(base.minimum_permission),
+        }
+    }
+}
+
 /// Require section of the configuration.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all(deserialize = "camelCase"))]
@@ -527,6 +2094,83 @@ impl Default for ConfigRequire {
     }
 }
 
+impl ConfigRequire {
+    /// Merge this section with the base one provided.
+    #[must_use]
+    pub fn merge(self, base: Self) -> Self {
+        Self {
+            members: self.members.or(base.members),
+        }
+    }
+}
+
+/// Permission of a collaborator on a repository, as returned by the
+/// collaborator permission endpoint (only the fields needed are deserialized).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CollaboratorPermission {
+    permission: String,
+    role_name: Option<String>,
+}
+
+/// Issue event, as returned by the issue events endpoint (only the fields
+/// needed are deserialized).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct IssueEvent {
+    actor: Option<IssueEventActor>,
+    event: String,
+    label: Option<IssueEventLabel>,
+}
+
+/// Actor that triggered an issue event.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct IssueEventActor {
+    login: String,
+}
+
+/// Label of a labeled or unlabeled issue event.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct IssueEventLabel {
+    name: String,
+}
+
+/// Permission a user has on a repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepositoryPermission {
+    None,
+    Read,
+    Triage,
+    Write,
+    Maintain,
+    Admin,
+}
+
+impl From<&CollaboratorPermission> for RepositoryPermission {
+    /// Convert a collaborator permission to RepositoryPermission. The role name
+    /// is used when it is a known one, as it reports the maintain and triage
+    /// roles. Custom roles fall back to the legacy permission they are based on.
+    fn from(collaborator_permission: &CollaboratorPermission) -> Self {
+        match collaborator_permission.role_name.as_deref().map(RepositoryPermission::from) {
+            Some(permission) if permission != RepositoryPermission::None => permission,
+            _ => RepositoryPermission::from(collaborator_permission.permission.as_str()),
+        }
+    }
+}
+
+impl From<&str> for RepositoryPermission {
+    /// Convert a GitHub permission name to RepositoryPermission.
+    fn from(permission: &str) -> Self {
+        match permission {
+            "admin" => RepositoryPermission::Admin,
+            "maintain" => RepositoryPermission::Maintain,
+            "write" | "push" => RepositoryPermission::Write,
+            "triage" => RepositoryPermission::Triage,
+            "read" | "pull" => RepositoryPermission::Read,
+            _ => RepositoryPermission::None,
+        }
+    }
+}
+
 /// User information.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct User {
@@ -548,12 +2192,24 @@ impl User {
     }
 }
 
+/// Check if the value provided matches the glob pattern. Invalid patterns
+/// never match.
+fn glob_matches(pattern: &str, value: &str) -> bool {
+    match Glob::new(pattern) {
+        Ok(glob) => glob.compile_matcher().is_match(value),
+        Err(err) => {
+            warn!(?err, pattern, "invalid glob pattern");
+            false
+        }
+    }
+}
+
 /// Input used to create a new check run.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NewCheckRunInput {
     pub actions: Vec<CheckRunAction>,
-    pub completed_at: DateTime<Utc>,
-    pub conclusion: CheckRunConclusion,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub conclusion: Option<CheckRunConclusion>,
     pub head_sha: String,
     pub name: String,
     pub started_at: DateTime<Utc>,
@@ -561,3 +2217,377 @@ pub struct NewCheckRunInput {
     pub summary: String,
     pub title: String,
 }
+
+/// Input used to create a new commit status.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewCommitStatusInput {
+    pub context: String,
+    pub description: String,
+    pub sha: String,
+    pub state: CommitStatusState,
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::{
+        CollaboratorPermission, Config, ConfigAllowRemediationCommits, ConfigBranches, ConfigCheckPushes,
+        ConfigError, ConfigMode, ConfigOverrideAllowedBy, ConfigPolicy, ConfigReportAs, ConfigRequire,
+        IssueEvent, RepositoryPermission,
+    };
+
+    #[test]
+    fn config_from_yaml_empty_document() {
+        assert!(Config::from_yaml("").unwrap().is_none());
+    }
+
+    #[test]
+    fn config_from_yaml_unknown_keys_collected() {
+        let config = Config::from_yaml(indoc! {r"
+            mode: advisory
+            allowOverideAction: true
+            require:
+              members: false
+              signoffs: true
+        "})
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(config.mode, Some(ConfigMode::Advisory));
+        assert_eq!(
+            config.unknown_keys,
+            vec!["allowOverideAction".to_string(), "require.signoffs".to_string()]
+        );
+    }
+
+    #[test]
+    fn config_from_yaml_parse_error_includes_location() {
+        let err = Config::from_yaml(indoc! {r"
+            require:
+              members: maybe
+        "})
+        .unwrap_err();
+
+        let ConfigError::Parse { line, column, .. } = &err else {
+            panic!("expected parse error");
+        };
+        assert_eq!(*line, Some(2));
+        assert_eq!(*column, Some(12));
+        assert!(err.to_string().starts_with("line 2, column 12: "));
+    }
+
+    #[test]
+    fn config_from_yaml_invalid_pattern() {
+        let err = Config::from_yaml(indoc! {r"
+            exemptPaths:
+              - docs/**
+              - src/[
+        "})
+        .unwrap_err();
+
+        let ConfigError::Validation(errors) = err else {
+            panic!("expected validation error");
+        };
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("invalid pattern `src/[` in `exemptPaths`"));
+    }
+
+    #[test]
+    fn config_from_yaml_invalid_extends() {
+        let err = Config::from_yaml("_extends: owner/\n").unwrap_err();
+
+        let ConfigError::Validation(errors) = err else {
+            panic!("expected validation error");
+        };
+        assert_eq!(
+            errors,
+            vec!["invalid repository `owner/` in `_extends`: expected `repo` or `owner/repo`".to_string()]
+        );
+    }
+
+    #[test]
+    fn config_push_check_enabled_on_matching_branches() {
+        let config = Config::from_yaml(indoc! {r"
+            checkPushes:
+              branches:
+                - main
+                - release-*
+        "})
+        .unwrap()
+        .unwrap();
+
+        assert!(config.push_check_is_enabled("main"));
+        assert!(config.push_check_is_enabled("release-1.0"));
+        assert!(!config.push_check_is_enabled("feature"));
+        assert!(!config.push_issue_is_enabled());
+        assert!(!Config::default().push_check_is_enabled("main"));
+    }
+
+    #[test]
+    fn config_merge_repository_keys_take_precedence() {
+        let base = Config::from_yaml(indoc! {r"
+            mode: advisory
+            squashMerge: true
+            require:
+              members: false
+        "})
+        .unwrap()
+        .unwrap();
+        let config = Config::from_yaml(indoc! {r"
+            _extends: org/dco-config
+            mode: enforce
+        "})
+        .unwrap()
+        .unwrap();
+
+        let merged = config.merge(base);
+        assert_eq!(merged.extends, Some("org/dco-config".to_string()));
+        assert_eq!(merged.mode, Some(ConfigMode::Enforce));
+        assert_eq!(merged.squash_merge, Some(true));
+        assert_eq!(merged.require, Some(ConfigRequire { members: Some(false) }));
+        assert_eq!(merged.exempt_paths, None);
+    }
+
+    #[test]
+    fn config_merge_sections_merged_key_by_key() {
+        let base = Config::from_yaml(indoc! {r"
+            allowRemediationCommits:
+              individual: true
+              thirdParty: true
+            branches:
+              exclude:
+                - dependabot/*
+            checkPushes:
+              branches:
+                - main
+            overrideAllowedBy:
+              minimumPermission: maintain
+        "})
+        .unwrap()
+        .unwrap();
+        let config = Config::from_yaml(indoc! {r"
+            allowRemediationCommits:
+              thirdParty: false
+            branches:
+              include:
+                - main
+            checkPushes:
+              openIssue: true
+            overrideAllowedBy:
+              users:
+                - user1
+        "})
+        .unwrap()
+        .unwrap();
+
+        let merged = config.merge(base);
+        assert_eq!(
+            merged.allow_remediation_commits,
+            Some(ConfigAllowRemediationCommits {
+                individual: Some(true),
+                third_party: Some(false),
+            })
+        );
+        assert_eq!(
+            merged.branches,
+            Some(ConfigBranches {
+                include: Some(vec!["main".to_string()]),
+                exclude: Some(vec!["dependabot/*".to_string()]),
+            })
+        );
+        assert_eq!(
+            merged.check_pushes,
+            Some(ConfigCheckPushes {
+                branches: Some(vec!["main".to_string()]),
+                open_issue: Some(true),
+            })
+        );
+        assert_eq!(
+            merged.override_allowed_by,
+            Some(ConfigOverrideAllowedBy {
+                users: Some(vec!["user1".to_string()]),
+                teams: None,
+                minimum_permission: Some(RepositoryPermission::Maintain),
+            })
+        );
+    }
+
+    #[test]
+    fn config_apply_policy_overridden_settings_tracked() {
+        let mut config = Config::from_yaml(indoc! {r"
+            allowOverrideAction: true
+            allowRemediationCommits:
+              individual: true
+              thirdParty: true
+            require:
+              members: false
+        "})
+        .unwrap()
+        .unwrap();
+        let policy = ConfigPolicy {
+            allow_override_action: Some(false),
+            allow_third_party_remediation_commits: Some(false),
+            mode: Some(ConfigMode::Enforce),
+            require_members: Some(true),
+            ..Default::default()
+        };
+
+        config.apply_policy(&policy);
+        assert_eq!(config.allow_override_action, Some(false));
+        assert_eq!(
+            config.allow_remediation_commits,
+            Some(ConfigAllowRemediationCommits {
+                individual: Some(true),
+                third_party: Some(false),
+            })
+        );
+        assert_eq!(config.mode, Some(ConfigMode::Enforce));
+        assert_eq!(config.require, Some(ConfigRequire { members: Some(true) }));
+        assert_eq!(
+            config.policy_overrides,
+            vec![
+                "allowOverrideAction".to_string(),
+                "allowRemediationCommits.thirdParty".to_string(),
+                "require.members".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn config_apply_policy_matching_settings_not_tracked() {
+        let mut config = Config::from_yaml("allowOverrideAction: false\n").unwrap().unwrap();
+        let policy = ConfigPolicy {
+            allow_override_action: Some(false),
+            squash_merge: Some(false),
+            ..Default::default()
+        };
+
+        config.apply_policy(&policy);
+        assert_eq!(config.allow_override_action, Some(false));
+        assert_eq!(config.squash_merge, Some(false));
+        assert!(config.policy_overrides.is_empty());
+    }
+
+    #[test]
+    fn config_apply_policy_exemptions_locked() {
+        let mut config = Config::from_yaml(indoc! {r#"
+            branches:
+              exclude:
+                - "*"
+            enforceAfter: 2999-01-01
+            exemptPaths:
+              - "**"
+            exemptionLabel: skip-dco
+        "#})
+        .unwrap()
+        .unwrap();
+        let policy = ConfigPolicy {
+            branches_locked: true,
+            enforce_after_locked: true,
+            exempt_paths_locked: true,
+            exemption_label_locked: true,
+            ..Default::default()
+        };
+
+        config.apply_policy(&policy);
+        assert!(config.branch_is_enforced("main"));
+        assert_eq!(config.enforce_after, None);
+        assert!(!config.exempt_paths_are_configured());
+        assert_eq!(config.exemption_label(), None);
+        assert_eq!(
+            config.policy_overrides,
+            vec![
+                "branches.exclude".to_string(),
+                "enforceAfter".to_string(),
+                "exemptPaths".to_string(),
+                "exemptionLabel".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn config_apply_policy_reporting_locked() {
+        let mut config = Config::from_yaml(indoc! {r"
+            deferDrafts: true
+            reportAs: commit_status
+        "})
+        .unwrap()
+        .unwrap();
+        let policy = ConfigPolicy {
+            defer_drafts: Some(false),
+            report_as: Some(ConfigReportAs::CheckRun),
+            ..Default::default()
+        };
+
+        config.apply_policy(&policy);
+        assert_eq!(config.defer_drafts, Some(false));
+        assert_eq!(config.report_as, Some(ConfigReportAs::CheckRun));
+        assert_eq!(
+            config.policy_overrides,
+            vec!["deferDrafts".to_string(), "reportAs".to_string()]
+        );
+    }
+
+    #[test]
+    fn issue_event_labeled_deserialized() {
+        let events: Vec<IssueEvent> = serde_json::from_str(indoc! {r#"
+            [
+                {
+                    "id": 1,
+                    "node_id": "node_id",
+                    "url": "url",
+                    "actor": { "login": "user1", "id": 1 },
+                    "event": "labeled",
+                    "commit_id": null,
+                    "commit_url": null,
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "label": { "name": "dco-exempt", "color": "ffffff" },
+                    "performed_via_github_app": null
+                },
+                {
+                    "id": 2,
+                    "node_id": "node_id",
+                    "url": "url",
+                    "actor": { "login": "user2", "id": 2 },
+                    "event": "renamed",
+                    "commit_id": null,
+                    "commit_url": null,
+                    "created_at": "2024-01-02T00:00:00Z",
+                    "rename": { "from": "old", "to": "new" },
+                    "performed_via_github_app": null
+                }
+            ]
+        "#})
+        .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event, "labeled");
+        assert_eq!(events[0].actor.as_ref().unwrap().login, "user1");
+        assert_eq!(events[0].label.as_ref().unwrap().name, "dco-exempt");
+        assert_eq!(events[1].event, "renamed");
+        assert!(events[1].label.is_none());
+    }
+
+    #[test]
+    fn repository_permission_from_collaborator_permission() {
+        let permission = |permission: &str, role_name: Option<&str>| {
+            RepositoryPermission::from(&CollaboratorPermission {
+                permission: permission.to_string(),
+                role_name: role_name.map(ToString::to_string),
+            })
+        };
+
+        assert_eq!(
+            permission("write", Some("maintain")),
+            RepositoryPermission::Maintain
+        );
+        assert_eq!(permission("read", Some("triage")), RepositoryPermission::Triage);
+        assert_eq!(permission("write", Some("write")), RepositoryPermission::Write);
+        assert_eq!(
+            permission("write", Some("custom-role")),
+            RepositoryPermission::Write
+        );
+        assert_eq!(permission("admin", None), RepositoryPermission::Admin);
+    }
+}
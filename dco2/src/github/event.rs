@@ -20,9 +20,14 @@ const EVENT_NAME_HEADER: &str = "X-GitHub-Event";
 /// Webhook event.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Event {
-    CheckRun(CheckRunEvent),
+    CheckRun(Box<CheckRunEvent>),
+    Installation(InstallationEvent),
+    InstallationRepositories(InstallationRepositoriesEvent),
+    IssueComment(IssueCommentEvent),
     MergeGroup(MergeGroupEvent),
-    PullRequest(PullRequestEvent),
+    Organization(OrganizationEvent),
+    PullRequest(Box<PullRequestEvent>),
+    Push(Box<PushEvent>),
 }
 
 impl TryFrom<(&HeaderMap, &Bytes)> for Event {
@@ -36,14 +41,34 @@ impl TryFrom<(&HeaderMap, &Bytes)> for Event {
                     let event = serde_json::from_slice(body).map_err(|_| EventError::InvalidPayload)?;
                     Ok(Event::CheckRun(event))
                 }
+                b"installation" => {
+                    let event = serde_json::from_slice(body).map_err(|_| EventError::InvalidPayload)?;
+                    Ok(Event::Installation(event))
+                }
+                b"installation_repositories" => {
+                    let event = serde_json::from_slice(body).map_err(|_| EventError::InvalidPayload)?;
+                    Ok(Event::InstallationRepositories(event))
+                }
+                b"issue_comment" => {
+                    let event = serde_json::from_slice(body).map_err(|_| EventError::InvalidPayload)?;
+                    Ok(Event::IssueComment(event))
+                }
                 b"merge_group" => {
                     let event = serde_json::from_slice(body).map_err(|_| EventError::InvalidPayload)?;
                     Ok(Event::MergeGroup(event))
                 }
+                b"organization" => {
+                    let event = serde_json::from_slice(body).map_err(|_| EventError::InvalidPayload)?;
+                    Ok(Event::Organization(event))
+                }
                 b"pull_request" => {
                     let event = serde_json::from_slice(body).map_err(|_| EventError::InvalidPayload)?;
                     Ok(Event::PullRequest(event))
                 }
+                b"push" => {
+                    let event = serde_json::from_slice(body).map_err(|_| EventError::InvalidPayload)?;
+                    Ok(Event::Push(event))
+                }
                 _ => Err(EventError::UnsupportedEvent),
             },
             None => Err(EventError::MissingHeader),
@@ -70,6 +95,7 @@ pub struct CheckRunEvent {
     pub installation: Installation,
     pub repository: Repository,
     pub requested_action: Option<RequestedAction>,
+    pub sender: Sender,
 }
 
 impl CheckRunEvent {
@@ -97,6 +123,23 @@ pub enum CheckRunEventAction {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CheckRunEventCheckRun {
     pub head_sha: String,
+    pub id: i64,
+    pub output: CheckRunEventCheckRunOutput,
+    pub pull_requests: Vec<CheckRunEventPullRequest>,
+}
+
+/// Check run event check run output details.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckRunEventCheckRunOutput {
+    pub summary: Option<String>,
+    pub title: Option<String>,
+}
+
+/// Check run event pull request details.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckRunEventPullRequest {
+    pub base: PullRequestBase,
+    pub number: i64,
 }
 
 /// GitHub application installation information.
@@ -105,12 +148,98 @@ pub struct Installation {
     pub id: i64,
 }
 
+/// Installation event payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstallationEvent {
+    pub action: InstallationEventAction,
+    pub installation: InstallationDetails,
+    pub repositories: Option<Vec<InstallationRepository>>,
+}
+
+/// Installation event action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallationEventAction {
+    Created,
+    Deleted,
+    #[serde(other)]
+    Other,
+}
+
+/// Installation repositories event payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstallationRepositoriesEvent {
+    pub action: InstallationRepositoriesEventAction,
+    pub installation: InstallationDetails,
+    pub repositories_added: Vec<InstallationRepository>,
+}
+
+/// Installation repositories event action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallationRepositoriesEventAction {
+    Added,
+    #[serde(other)]
+    Other,
+}
+
+/// GitHub application installation details (as provided in installation
+/// related events).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstallationDetails {
+    pub account: InstallationAccount,
+    pub id: i64,
+}
+
+impl InstallationDetails {
+    /// Get context information for the repository provided.
+    pub fn ctx(&self, repository: &InstallationRepository) -> Ctx {
+        Ctx {
+            inst_id: self.id,
+            owner: self.account.login.to_string(),
+            repo: repository.name.to_string(),
+        }
+    }
+
+    /// Get the organization the application was installed on (if any).
+    pub fn org(&self) -> Option<&str> {
+        match self.account.type_ {
+            InstallationAccountType::Organization => Some(&self.account.login),
+            _ => None,
+        }
+    }
+}
+
+/// Account the GitHub application was installed on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstallationAccount {
+    pub login: String,
+    #[serde(rename = "type")]
+    pub type_: InstallationAccountType,
+}
+
+/// Installation account type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum InstallationAccountType {
+    Organization,
+    User,
+    #[serde(other)]
+    Other,
+}
+
+/// Repository information (as provided in installation related events).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstallationRepository {
+    pub name: String,
+}
+
 /// Merge group event payload.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MergeGroupEvent {
     pub action: MergeGroupEventAction,
     pub installation: Installation,
     pub merge_group: MergeGroupEventMergeGroup,
+    pub organization: Option<Organization>,
     pub repository: Repository,
 }
 
@@ -137,6 +266,7 @@ pub enum MergeGroupEventAction {
 /// Merge group event merge group details.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MergeGroupEventMergeGroup {
+    pub base_sha: String,
     pub head_commit: MergeGroupHeadCommit,
 }
 
@@ -146,18 +276,174 @@ pub struct MergeGroupHeadCommit {
     pub id: String,
 }
 
+/// Issue information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Issue {
+    pub number: i64,
+    pub pull_request: Option<IssuePullRequest>,
+}
+
+/// Issue comment information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueComment {
+    pub body: String,
+    pub html_url: String,
+}
+
+/// Issue comment event payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueCommentEvent {
+    pub action: IssueCommentEventAction,
+    pub comment: IssueComment,
+    pub installation: Installation,
+    pub issue: Issue,
+    pub organization: Option<Organization>,
+    pub repository: Repository,
+    pub sender: Sender,
+}
+
+impl IssueCommentEvent {
+    /// Get context information from event details.
+    pub fn ctx(&self) -> Ctx {
+        Ctx {
+            inst_id: self.installation.id,
+            owner: self.repository.owner.login.to_string(),
+            repo: self.repository.name.to_string(),
+        }
+    }
+}
+
+/// Issue comment event action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueCommentEventAction {
+    Created,
+    #[serde(other)]
+    Other,
+}
+
+/// Issue pull request information (only present when the issue is a pull
+/// request).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssuePullRequest {
+    pub html_url: String,
+}
+
+/// Label information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Label {
+    pub name: String,
+}
+
 /// GitHub organization information.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Organization {
     pub login: String,
 }
 
+/// Organization event payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrganizationEvent {
+    pub action: OrganizationEventAction,
+    pub installation: Installation,
+    pub membership: Option<OrganizationMembership>,
+    pub organization: Organization,
+}
+
+/// Organization event action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationEventAction {
+    MemberAdded,
+    MemberRemoved,
+    #[serde(other)]
+    Other,
+}
+
+/// Organization membership information (only present in member related
+/// organization events).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrganizationMembership {
+    pub user: OrganizationMember,
+}
+
+/// Organization member information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrganizationMember {
+    pub login: String,
+}
+
 /// Pull request information.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PullRequest {
     pub base: PullRequestBase,
+    pub body: Option<String>,
+    pub draft: bool,
     pub head: PullRequestHead,
     pub html_url: String,
+    pub labels: Vec<Label>,
+    pub number: i64,
+    pub title: String,
+}
+
+impl PullRequest {
+    /// Get the message of the commit that would result from squashing the
+    /// pull request (its title followed by its body).
+    pub fn squash_commit_message(&self) -> String {
+        match self.body.as_deref() {
+            Some(body) if !body.trim().is_empty() => format!("{}\n\n{}", self.title, body),
+            _ => self.title.clone(),
+        }
+    }
+
+    /// Check if the pull request has the label provided.
+    pub fn has_label(&self, label: &str) -> bool {
+        self.labels.iter().any(|l| l.name == label)
+    }
+}
+
+impl From<octorust::types::PullRequestSimple> for PullRequest {
+    /// Convert octorust pull request simple to PullRequest.
+    fn from(pr: octorust::types::PullRequestSimple) -> Self {
+        Self {
+            base: PullRequestBase {
+                ref_: pr.base.ref_,
+                sha: pr.base.sha,
+            },
+            body: Some(pr.body).filter(|body| !body.is_empty()),
+            draft: pr.draft,
+            head: PullRequestHead {
+                ref_: pr.head.ref_,
+                sha: pr.head.sha,
+            },
+            html_url: pr.html_url,
+            labels: pr.labels.into_iter().map(|l| Label { name: l.name }).collect(),
+            number: pr.number,
+            title: pr.title,
+        }
+    }
+}
+
+impl From<octorust::types::PullRequestData> for PullRequest {
+    /// Convert octorust pull request data to PullRequest.
+    fn from(pr: octorust::types::PullRequestData) -> Self {
+        Self {
+            base: PullRequestBase {
+                ref_: pr.base.ref_,
+                sha: pr.base.sha,
+            },
+            body: Some(pr.body).filter(|body| !body.is_empty()),
+            draft: pr.draft,
+            head: PullRequestHead {
+                ref_: pr.head.ref_,
+                sha: pr.head.sha,
+            },
+            html_url: pr.html_url,
+            labels: pr.labels.into_iter().map(|l| Label { name: l.name }).collect(),
+            number: pr.number,
+            title: pr.title,
+        }
+    }
 }
 
 /// Pull request base information.
@@ -172,10 +458,13 @@ pub struct PullRequestBase {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PullRequestEvent {
     pub action: PullRequestEventAction,
+    pub changes: Option<PullRequestEventChanges>,
     pub installation: Installation,
+    pub label: Option<Label>,
     pub organization: Option<Organization>,
     pub pull_request: PullRequest,
     pub repository: Repository,
+    pub sender: Sender,
 }
 
 impl PullRequestEvent {
@@ -193,12 +482,37 @@ impl PullRequestEvent {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PullRequestEventAction {
+    Edited,
+    Labeled,
     Opened,
+    ReadyForReview,
     Synchronize,
+    Unlabeled,
     #[serde(other)]
     Other,
 }
 
+/// Pull request event changes (only present in edited events).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequestEventChanges {
+    pub body: Option<PullRequestEventChange>,
+    pub title: Option<PullRequestEventChange>,
+}
+
+impl PullRequestEventChanges {
+    /// Check if the pull request description (title or body) has changed.
+    pub fn description_changed(&self) -> bool {
+        self.body.is_some() || self.title.is_some()
+    }
+}
+
+/// Pull request event change details. The previous value is not set when
+/// the field was empty (i.e. a body added to a pull request without one).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequestEventChange {
+    pub from: Option<String>,
+}
+
 /// Pull request head information.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PullRequestHead {
@@ -207,6 +521,73 @@ pub struct PullRequestHead {
     pub sha: String,
 }
 
+/// Push event payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PushEvent {
+    pub after: String,
+    pub before: String,
+    pub commits: Vec<PushEventCommit>,
+    pub created: bool,
+    pub deleted: bool,
+    pub installation: Installation,
+    pub organization: Option<Organization>,
+    #[serde(rename = "ref")]
+    pub ref_: String,
+    pub repository: PushEventRepository,
+    pub sender: Sender,
+}
+
+impl PushEvent {
+    /// Get context information from event details.
+    pub fn ctx(&self) -> Ctx {
+        Ctx {
+            inst_id: self.installation.id,
+            owner: self.repository.owner.login.to_string(),
+            repo: self.repository.name.to_string(),
+        }
+    }
+
+    /// Check if any of the files provided were added, modified or removed by
+    /// the commits pushed.
+    pub fn touches_any(&self, files: &[&str]) -> bool {
+        self.commits.iter().any(|commit| {
+            commit
+                .added
+                .iter()
+                .chain(&commit.modified)
+                .chain(&commit.removed)
+                .any(|file| files.contains(&file.as_str()))
+        })
+    }
+
+    /// Get the name of the branch the push was to (if any, as tags can be
+    /// pushed as well).
+    pub fn branch(&self) -> Option<&str> {
+        self.ref_.strip_prefix("refs/heads/")
+    }
+
+    /// Check if the push was to the repository's default branch.
+    pub fn is_to_default_branch(&self) -> bool {
+        self.branch() == Some(self.repository.default_branch.as_str())
+    }
+}
+
+/// Push event commit information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PushEventCommit {
+    pub added: Vec<String>,
+    pub modified: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+/// Push event repository information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PushEventRepository {
+    pub default_branch: String,
+    pub name: String,
+    pub owner: RepositoryOwner,
+}
+
 /// Repository information.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Repository {
@@ -225,3 +606,9 @@ pub struct RepositoryOwner {
 pub struct RequestedAction {
     pub identifier: String,
 }
+
+/// Event sender information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sender {
+    pub login: String,
+}